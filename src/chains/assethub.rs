@@ -1,17 +1,34 @@
+use subxt::utils::AccountId32;
 use subxt::{ OnlineClient, PolkadotConfig };
 use subxt_signer::sr25519::Keypair;
 
 use crate::config::{ assethub, get_rpc_urls };
+use crate::config::assethub::runtime_types::asset_hub_paseo_runtime::RuntimeCall;
 use crate::config::assethub::runtime_types::bounded_collections::bounded_vec::BoundedVec;
-use crate::utils::print_success;
+use crate::config::assethub::runtime_types::pallet_nfts::pallet::Call as NftsCall;
+use crate::utils::{ print_info, print_success };
 use crate::config::assethub::runtime_types::pallet_nfts::types::{
+    AttributeNamespace,
     BitFlags1,
     CollectionConfig,
+    ItemSetting,
     MintSettings,
     MintType,
 };
+use crate::chains::{ check_article_exists, estimate_fee, submit_and_watch, EduChainClient };
 use crate::error::EduNewsError;
-use crate::types::Network;
+use crate::journal::RegistrationJournal;
+use crate::types::{
+    ArticleNftMetadata,
+    CollectionInfo,
+    LockState,
+    Network,
+    NftAttributes,
+    PlannedCost,
+    PublicationProfile,
+    RollbackReport,
+    TxReceipt,
+};
 
 pub type AssetHubClient = OnlineClient<PolkadotConfig>;
 
@@ -23,167 +40,999 @@ pub async fn create_assethub_client(network: Network) -> Result<AssetHubClient,
         ::from_url(&rpc_url).await
         .map_err(|e| EduNewsError::ChainConnection {
             chain: "AssetHub".to_string(),
-            source: e,
+            source: Box::new(e),
         })
 }
 
 /// Create NFT collection and mint article NFT
+///
+/// Every AssetHub call still missing from the registration journal (create
+/// and configure the collection, mint, set metadata and attributes) is sent
+/// as one atomic `utility.batch_all`, so registration waits for a single
+/// finalization and a failure leaves nothing half-configured.
+///
+/// The target collection is looked up again before every attempt, so after
+/// losing a race to create it, the collection that now exists is used
+/// instead of creating a second one.
+///
+/// Returns the collection and item IDs along with the receipt of the mint.
+#[allow(clippy::too_many_arguments)]
 pub async fn create_nft(
     client: &AssetHubClient,
+    educhain_client: &EduChainClient,
     keypair: &Keypair,
-    title: &str,
-    content_hash: &str
-) -> Result<(u32, u32), EduNewsError> {
-    // First ensure collection exists for publisher
-    let collection_id = ensure_collection_exists(client, keypair).await?;
+    metadata: &ArticleNftMetadata,
+    attributes: &NftAttributes,
+    target: &CollectionTarget,
+    profile: &PublicationProfile,
+    journal: &mut RegistrationJournal
+) -> Result<(u32, u32, TxReceipt), EduNewsError> {
+    let mut start_from = 0;
+    let mut attempt = 1;
+
+    loop {
+        // No-op once the journal holds a collection
+        resolve_target_collection(client, keypair, target, journal).await?;
+
+        let plan = plan_nft_calls(
+            client,
+            educhain_client,
+            keypair,
+            metadata,
+            attributes,
+            profile,
+            journal,
+            start_from
+        ).await?;
+
+        // Everything is journaled already
+        if plan.calls.is_empty() {
+            if let Some(receipt) = journal.mint_receipt.clone() {
+                return Ok((plan.collection_id, plan.item_id, receipt));
+            }
+        }
+
+        let call_count = plan.calls.len();
+        let batch_tx = assethub::tx().utility().batch_all(plan.calls);
+
+        let (events, receipt) = match submit_and_watch(client, &batch_tx, keypair).await {
+            Ok(submitted) => submitted,
+            // Another publisher took the predicted item ID: try the next one
+            Err(EduNewsError::NftAlreadyExists) if attempt < MAX_MINT_ATTEMPTS => {
+                print_info(&format!("Item {} was taken concurrently, retrying with the next free ID", plan.item_id));
+                start_from = plan.item_id + 1;
+                attempt += 1;
+                continue;
+            }
+            // Another collection was created first, so the predicted collection ID isn't ours
+            Err(EduNewsError::NftNoPermission) if plan.creates_collection && attempt < MAX_MINT_ATTEMPTS => {
+                print_info(&format!("Collection {} was taken concurrently, retrying", plan.collection_id));
+                attempt += 1;
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
 
-    // Then mint NFT for the article
-    let item_id = mint_article_nft(client, keypair, collection_id, title, content_hash).await?;
+        // batch_all is atomic, so one BatchCompleted means every call went through
+        events
+            .find_first::<assethub::utility::events::BatchCompleted>()?
+            .ok_or_else(|| EduNewsError::MissingEvent {
+                chain: "AssetHub".to_string(),
+                event: "Utility.BatchCompleted".to_string(),
+            })?;
 
-    Ok((collection_id, item_id))
+        // Confirm the IDs that were actually assigned from the per-call events
+        if let Some(created) = events.find_first::<assethub::nfts::events::Created>()? {
+            if created.collection != plan.collection_id {
+                return Err(EduNewsError::CollectionMismatch {
+                    expected: plan.collection_id,
+                    actual: created.collection,
+                });
+            }
+            print_success(&format!("Created NFT collection {} on AssetHub", created.collection));
+        }
+
+        let issued = events.find_first::<assethub::nfts::events::Issued>()?;
+        let collection_id = issued.as_ref().map_or(plan.collection_id, |issued| issued.collection);
+        journal.collection_id = Some(collection_id);
+        journal.collection_metadata_set = true;
+
+        if let Some(issued) = issued {
+            journal.item_id = Some(issued.item);
+            journal.mint_receipt = Some(receipt.clone());
+        }
+        journal.item_metadata_set = true;
+        journal.item_attributes_set = true;
+        journal.save()?;
+
+        let item_id = journal.item_id.ok_or_else(|| EduNewsError::MissingEvent {
+            chain: "AssetHub".to_string(),
+            event: "Nfts.Issued".to_string(),
+        })?;
+        print_success(
+            &format!(
+                "Minted NFT on AssetHub: collection {}, item {} ({} calls batched) in block #{}",
+                collection_id,
+                item_id,
+                call_count,
+                receipt.block_number
+            )
+        );
+
+        let mint_receipt = journal.mint_receipt.clone().unwrap_or(receipt);
+        return Ok((collection_id, item_id, mint_receipt));
+    }
 }
 
-/// Ensure publisher collection exists, create if needed
-pub async fn ensure_collection_exists(
+/// Which of the publisher's collections an article is minted into
+pub enum CollectionTarget {
+    /// The publisher's lowest-numbered collection, created on first use
+    Default,
+    /// A specific collection, which must be owned by the publisher
+    Pinned(u32),
+    /// The publisher's collection with this publication name, created on first use
+    Publication(String),
+}
+
+/// Put the existing target collection, if any, into the journal
+///
+/// The journal isn't saved: nothing has been sent yet.
+pub async fn resolve_target_collection(
     client: &AssetHubClient,
-    keypair: &Keypair
-) -> Result<u32, EduNewsError> {
-    // Get the next available collection ID from storage
-    let next_collection_id_query = assethub::storage().nfts().next_collection_id();
-    let next_collection_id = client
-        .storage()
-        .at_latest().await?
-        .fetch(&next_collection_id_query).await?
-        .unwrap_or(0u32);
+    keypair: &Keypair,
+    target: &CollectionTarget,
+    journal: &mut RegistrationJournal
+) -> Result<(), EduNewsError> {
+    if journal.collection_id.is_none() {
+        if let Some(collection_id) = find_target_collection(client, keypair, target).await? {
+            journal.collection_id = Some(collection_id);
+            journal.collection_metadata_set = true; // Configured when it was created
+        }
+    }
+    Ok(())
+}
 
-    // Check if we already have a collection for this publisher
-    // We'll iterate through existing collections to find one owned by this keypair
-    let mut publisher_collection_id: Option<u32> = None;
+/// Find the existing collection an article should be minted into
+///
+/// A pinned collection is used as-is once its ownership is confirmed; a
+/// publication is looked up by the name in its collection metadata;
+/// otherwise the publisher's existing collection is looked up. `None`
+/// means a new collection has to be created.
+pub async fn find_target_collection(
+    client: &AssetHubClient,
+    keypair: &Keypair,
+    target: &CollectionTarget
+) -> Result<Option<u32>, EduNewsError> {
+    let found = match target {
+        CollectionTarget::Pinned(collection_id) => {
+            check_collection_owner(client, keypair, *collection_id).await?;
+            print_success(&format!("Using pinned NFT collection {} on AssetHub", collection_id));
+            return Ok(Some(*collection_id));
+        }
+        CollectionTarget::Publication(name) => {
+            find_publication_collection(client, &keypair.public_key().into(), name).await?
+        }
+        CollectionTarget::Default => find_publisher_collection(client, keypair).await?,
+    };
+
+    if let Some(collection_id) = found {
+        print_success(&format!("Using existing NFT collection {} on AssetHub", collection_id));
+    }
+    Ok(found)
+}
 
-    for collection_id in 0..next_collection_id {
-        let collection_query = assethub::storage().nfts().collection(collection_id);
-        if
-            let Some(collection_details) = client
+/// The AssetHub calls still needed to bring an article NFT into existence
+pub struct NftPlan {
+    /// Existing or predicted collection ID
+    pub collection_id: u32,
+    /// Existing or predicted item ID
+    pub item_id: u32,
+    /// Whether the calls create a new collection
+    pub creates_collection: bool,
+    pub calls: Vec<RuntimeCall>,
+    /// Deposits the calls reserve, from the pallet_nfts constants
+    pub deposits: Vec<PlannedCost>,
+}
+
+/// How often the batch is rebuilt after losing a predicted ID to a concurrent transaction
+const MAX_MINT_ATTEMPTS: u32 = 5;
+
+/// Build the calls for every step missing from the journal, without sending anything
+///
+/// Calls inside one batch can't read each other's results, so the new
+/// collection ID is predicted from `NextCollectionId` and the item ID is
+/// probed in storage from `start_from` on. A wrong prediction makes the
+/// whole batch fail atomically, and the caller retries.
+#[allow(clippy::too_many_arguments)]
+pub async fn plan_nft_calls(
+    client: &AssetHubClient,
+    educhain_client: &EduChainClient,
+    keypair: &Keypair,
+    metadata: &ArticleNftMetadata,
+    attributes: &NftAttributes,
+    profile: &PublicationProfile,
+    journal: &RegistrationJournal,
+    start_from: u32
+) -> Result<NftPlan, EduNewsError> {
+    let owner: AccountId32 = keypair.public_key().into();
+    let mut calls = Vec::new();
+    let mut deposits = Vec::new();
+
+    let constants = assethub::constants().nfts();
+    let metadata_deposit_base = client.constants().at(&constants.metadata_deposit_base())?;
+    let attribute_deposit_base = client.constants().at(&constants.attribute_deposit_base())?;
+    let deposit_per_byte = client.constants().at(&constants.deposit_per_byte())?;
+
+    let (collection_id, creates_collection) = match journal.collection_id {
+        Some(collection_id) => (collection_id, false),
+        None => {
+            let next_query = assethub::storage().nfts().next_collection_id();
+            let next_collection_id = client
                 .storage()
                 .at_latest().await?
-                .fetch(&collection_query).await?
-        {
-            // Check if this collection is owned by our keypair
-            if collection_details.owner == keypair.public_key().into() {
-                publisher_collection_id = Some(collection_id);
-                break;
-            }
+                .fetch(&next_query).await?
+                .unwrap_or_default();
+            calls.push(create_collection_call(&owner, profile));
+            deposits.push(PlannedCost {
+                description: "Collection deposit".to_string(),
+                amount: client.constants().at(&constants.collection_deposit())?,
+            });
+            (next_collection_id, true)
         }
+    };
+
+    if !journal.collection_metadata_set {
+        let data = encode_collection_metadata(client, profile)?;
+        deposits.push(PlannedCost {
+            description: "Collection metadata deposit".to_string(),
+            amount: metadata_deposit_base + deposit_per_byte * (data.len() as u128),
+        });
+        calls.push(
+            RuntimeCall::Nfts(NftsCall::set_collection_metadata {
+                collection: collection_id,
+                data: BoundedVec(data),
+            })
+        );
     }
 
-    if let Some(collection_id) = publisher_collection_id {
-        print_success(&format!("Using existing NFT collection {} on AssetHub", collection_id));
-        Ok(collection_id)
-    } else {
-        // Create new collection using the next available ID
-        let collection_id = next_collection_id;
-
-        let create_tx = assethub
-            ::tx()
-            .nfts()
-            .create(subxt::utils::MultiAddress::Id(keypair.public_key().into()), {
-                CollectionConfig {
-                    settings: {
-                        BitFlags1(0, std::marker::PhantomData)
-                    },
-                    max_supply: None,
-                    mint_settings: {
-                        MintSettings {
-                            mint_type: MintType::Issuer,
-                            price: None,
-                            start_block: None,
-                            end_block: None,
-                            default_item_settings: {
-                                BitFlags1(1, std::marker::PhantomData) // 1 = Transferable bit flag
-                            },
-                            __ignore: std::marker::PhantomData,
-                        }
-                    },
+    let item_id = match journal.item_id {
+        Some(item_id) => item_id,
+        None => {
+            // A new collection starts at item 0
+            let item_id = if creates_collection {
+                0
+            } else {
+                get_next_item_id(client, educhain_client, collection_id, start_from).await?
+            };
+            calls.push(
+                RuntimeCall::Nfts(NftsCall::mint {
+                    collection: collection_id,
+                    item: item_id,
+                    mint_to: subxt::utils::MultiAddress::Id(owner.clone()),
+                    witness_data: None,
+                })
+            );
+            deposits.push(PlannedCost {
+                description: "Item deposit".to_string(),
+                amount: client.constants().at(&constants.item_deposit())?,
+            });
+            item_id
+        }
+    };
+
+    if !journal.item_metadata_set {
+        let data = encode_nft_metadata(client, metadata)?;
+        deposits.push(PlannedCost {
+            description: "Item metadata deposit".to_string(),
+            amount: metadata_deposit_base + deposit_per_byte * (data.len() as u128),
+        });
+        calls.push(
+            RuntimeCall::Nfts(NftsCall::set_metadata {
+                collection: collection_id,
+                item: item_id,
+                data: BoundedVec(data),
+            })
+        );
+    }
+
+    if !journal.item_attributes_set {
+        for (key, value) in attributes.entries() {
+            deposits.push(PlannedCost {
+                description: format!("Attribute deposit ({})", key),
+                amount: attribute_deposit_base + deposit_per_byte * ((key.len() + value.len()) as u128),
+            });
+            calls.push(
+                RuntimeCall::Nfts(NftsCall::set_attribute {
+                    collection: collection_id,
+                    maybe_item: Some(item_id),
+                    namespace: AttributeNamespace::CollectionOwner,
+                    key: BoundedVec(key.as_bytes().to_vec()),
+                    value: BoundedVec(value.into_bytes()),
+                })
+            );
+        }
+    }
+
+    Ok(NftPlan {
+        collection_id,
+        item_id,
+        creates_collection,
+        calls,
+        deposits,
+    })
+}
+
+/// Find a collection owned by the publisher keypair, if any
+///
+/// Picks the lowest collection ID so repeated runs keep using the same one.
+pub async fn find_publisher_collection(
+    client: &AssetHubClient,
+    keypair: &Keypair
+) -> Result<Option<u32>, EduNewsError> {
+    let collections = get_publisher_collections(client, &keypair.public_key().into()).await?;
+    Ok(collections.into_iter().min())
+}
+
+/// Find the owner's collection whose publication profile has this name
+///
+/// Picks the lowest collection ID if several share the name.
+pub async fn find_publication_collection(
+    client: &AssetHubClient,
+    owner: &AccountId32,
+    name: &str
+) -> Result<Option<u32>, EduNewsError> {
+    let publications = get_publications(client, owner).await?;
+    Ok(
+        publications
+            .into_iter()
+            .find(|info| info.publication_name() == Some(name))
+            .map(|info| info.collection_id)
+    )
+}
+
+/// Details of every collection owned by an account, by collection ID
+pub async fn get_publications(
+    client: &AssetHubClient,
+    owner: &AccountId32
+) -> Result<Vec<CollectionInfo>, EduNewsError> {
+    let mut collections = get_publisher_collections(client, owner).await?;
+    collections.sort_unstable();
+
+    let mut publications = Vec::new();
+    for collection_id in collections {
+        if let Some(info) = get_collection_info(client, collection_id).await? {
+            publications.push(info);
+        }
+    }
+
+    Ok(publications)
+}
+
+/// List every collection owned by an account in a single prefix iteration
+///
+/// Storage Type: CollectionAccount - StorageDoubleMap<AccountId32, CollectionId, ()>
+/// pallet_nfts keeps this owner index up to date on create and ownership
+/// transfer, so iterating over the account's prefix finds its collections
+/// without fetching every collection on the chain.
+pub async fn get_publisher_collections(
+    client: &AssetHubClient,
+    owner: &AccountId32
+) -> Result<Vec<u32>, EduNewsError> {
+    let owner_query = assethub::storage().nfts().collection_account_iter1(owner.clone());
+
+    let mut results = client.storage().at_latest().await?.iter(owner_query).await?;
+
+    let mut collections = Vec::new();
+    while let Some(entry) = results.next().await {
+        let entry = entry?;
+        // Both keys are Blake2_128Concat, so the raw collection ID (u32, little
+        // endian) is appended unhashed at the very end of the storage key
+        let key_len = entry.key_bytes.len();
+        if key_len >= 4 {
+            let mut id_bytes = [0u8; 4];
+            id_bytes.copy_from_slice(&entry.key_bytes[key_len - 4..]);
+            collections.push(u32::from_le_bytes(id_bytes));
+        }
+    }
+
+    Ok(collections)
+}
+
+/// Check that a pinned collection exists and is owned by the publisher
+pub async fn check_collection_owner(
+    client: &AssetHubClient,
+    keypair: &Keypair,
+    collection_id: u32
+) -> Result<(), EduNewsError> {
+    let collection_query = assethub::storage().nfts().collection(collection_id);
+
+    let collection_details = client
+        .storage()
+        .at_latest().await?
+        .fetch(&collection_query).await?
+        .ok_or(EduNewsError::CollectionNotFound { collection_id })?;
+
+    if collection_details.owner != keypair.public_key().into() {
+        return Err(EduNewsError::NftNoPermission);
+    }
+
+    Ok(())
+}
+
+/// Call creating a new NFT collection owned by the publisher
+///
+/// The profile's max supply and transferability become the collection config.
+fn create_collection_call(owner: &AccountId32, profile: &PublicationProfile) -> RuntimeCall {
+    RuntimeCall::Nfts(NftsCall::create {
+        admin: subxt::utils::MultiAddress::Id(owner.clone()),
+        config: CollectionConfig {
+            settings: {
+                BitFlags1(0, std::marker::PhantomData)
+            },
+            max_supply: profile.max_supply,
+            mint_settings: {
+                MintSettings {
+                    mint_type: MintType::Issuer,
+                    price: None,
+                    start_block: None,
+                    end_block: None,
+                    default_item_settings: default_item_settings(profile.transferable.unwrap_or(true)),
                     __ignore: std::marker::PhantomData,
                 }
-            });
+            },
+            __ignore: std::marker::PhantomData,
+        },
+    })
+}
+
+/// Item settings for newly minted items
+///
+/// Set bits *disable* item settings (1 = Transferable), so 0 keeps items
+/// transferable with unlocked metadata and attributes.
+fn default_item_settings(transferable: bool) -> BitFlags1<ItemSetting> {
+    BitFlags1(if transferable { 0 } else { 1 }, std::marker::PhantomData)
+}
+
+/// Set the publication profile as the collection metadata
+pub async fn set_collection_metadata(
+    client: &AssetHubClient,
+    keypair: &Keypair,
+    collection_id: u32,
+    profile: &PublicationProfile
+) -> Result<TxReceipt, EduNewsError> {
+    let metadata = BoundedVec(encode_collection_metadata(client, profile)?);
+    let set_metadata_tx = assethub
+        ::tx()
+        .nfts()
+        .set_collection_metadata(collection_id, metadata);
+
+    let (_, receipt) = submit_and_watch(client, &set_metadata_tx, keypair).await?;
+
+    print_success(&format!("Set '{}' metadata on NFT collection {}", profile.name, collection_id));
+    Ok(receipt)
+}
+
+/// Serialize a publication profile and check it against pallet_nfts' StringLimit
+pub fn encode_collection_metadata(
+    client: &AssetHubClient,
+    profile: &PublicationProfile
+) -> Result<Vec<u8>, EduNewsError> {
+    check_string_limit(client, serde_json::to_vec(profile)?)
+}
+
+/// Change the maximum number of items a collection may hold
+pub async fn set_collection_max_supply(
+    client: &AssetHubClient,
+    keypair: &Keypair,
+    collection_id: u32,
+    max_supply: u32
+) -> Result<TxReceipt, EduNewsError> {
+    let max_supply_tx = assethub::tx().nfts().set_collection_max_supply(collection_id, max_supply);
+
+    let (_, receipt) = submit_and_watch(client, &max_supply_tx, keypair).await?;
+
+    print_success(&format!("Set max supply of NFT collection {} to {}", collection_id, max_supply));
+    Ok(receipt)
+}
+
+/// Change whether items minted from now on are transferable
+///
+/// Only the default item settings in the mint settings change; items that
+/// already exist keep their own config.
+pub async fn set_default_transferable(
+    client: &AssetHubClient,
+    keypair: &Keypair,
+    collection_id: u32,
+    transferable: bool
+) -> Result<TxReceipt, EduNewsError> {
+    let config_query = assethub::storage().nfts().collection_config_of(collection_id);
+    let mut mint_settings = client
+        .storage()
+        .at_latest().await?
+        .fetch(&config_query).await?
+        .ok_or(EduNewsError::CollectionNotFound { collection_id })?
+        .mint_settings;
+    mint_settings.default_item_settings = default_item_settings(transferable);
+
+    let mint_settings_tx = assethub::tx().nfts().update_mint_settings(collection_id, mint_settings);
+
+    let (_, receipt) = submit_and_watch(client, &mint_settings_tx, keypair).await?;
+
+    print_success(&format!("Updated default item settings of NFT collection {}", collection_id));
+    Ok(receipt)
+}
 
-        let _events = client
-            .tx()
-            .sign_and_submit_then_watch_default(&create_tx, keypair).await?
-            .wait_for_finalized_success().await?;
+/// Read a collection's owner, config and publication profile
+///
+/// Storage Types: Collection, CollectionConfigOf and CollectionMetadataOf,
+/// all keyed by CollectionId.
+pub async fn get_collection_info(
+    client: &AssetHubClient,
+    collection_id: u32
+) -> Result<Option<CollectionInfo>, EduNewsError> {
+    let storage = client.storage().at_latest().await?;
+
+    let collection_query = assethub::storage().nfts().collection(collection_id);
+    let collection_details = match storage.fetch(&collection_query).await? {
+        Some(collection_details) => collection_details,
+        None => return Ok(None),
+    };
+
+    let config_query = assethub::storage().nfts().collection_config_of(collection_id);
+    let collection_config = storage.fetch(&config_query).await?;
+
+    let metadata_query = assethub::storage().nfts().collection_metadata_of(collection_id);
+    let metadata = storage.fetch(&metadata_query).await?.map(|metadata| metadata.data.0);
+
+    // Collections created before publication profiles carry plain text metadata
+    let (profile, raw_metadata) = match metadata {
+        Some(data) => match PublicationProfile::parse(&data) {
+            Some(profile) => (Some(profile), None),
+            None => (None, Some(String::from_utf8_lossy(&data).into_owned())),
+        },
+        None => (None, None),
+    };
+
+    Ok(
+        Some(CollectionInfo {
+            collection_id,
+            owner: collection_details.owner.to_string(),
+            items: collection_details.items,
+            max_supply: collection_config.as_ref().and_then(|config| config.max_supply),
+            transferable: collection_config
+                .as_ref()
+                .is_none_or(|config| config.mint_settings.default_item_settings.0 & 1 == 0),
+            profile,
+            raw_metadata,
+        })
+    )
+}
 
-        // Set collection metadata to "news"
-        let metadata = BoundedVec("news".as_bytes().to_vec());
-        let set_metadata_tx = assethub
-            ::tx()
-            .nfts()
-            .set_collection_metadata(collection_id, metadata);
+/// Serialize article metadata and check it against pallet_nfts' StringLimit
+///
+/// Run this before minting, so an oversized title or URL is caught before
+/// any deposit is taken.
+pub fn encode_nft_metadata(
+    client: &AssetHubClient,
+    metadata: &ArticleNftMetadata
+) -> Result<Vec<u8>, EduNewsError> {
+    check_string_limit(client, serde_json::to_vec(metadata)?)
+}
 
-        let _metadata_events = client
-            .tx()
-            .sign_and_submit_then_watch_default(&set_metadata_tx, keypair).await?
-            .wait_for_finalized_success().await?;
+/// Item and collection metadata share pallet_nfts' StringLimit
+fn check_string_limit(client: &AssetHubClient, bytes: Vec<u8>) -> Result<Vec<u8>, EduNewsError> {
+    let string_limit = client.constants().at(&assethub::constants().nfts().string_limit())?;
 
-        print_success(&format!("Created NFT collection {} with 'news' metadata on AssetHub", collection_id));
-        Ok(collection_id)
+    if bytes.len() > (string_limit as usize) {
+        return Err(EduNewsError::NftMetadataTooLarge {
+            len: bytes.len(),
+            max: string_limit,
+        });
     }
+
+    Ok(bytes)
 }
 
-/// Mint NFT for article
-pub async fn mint_article_nft(
+/// Attribute keys read from article NFTs, in the collection owner's namespace
+pub const ATTRIBUTE_KEYS: [&str; 6] = [
+    "content_hash",
+    "hash_algo",
+    "educhain_genesis",
+    "canonical_url",
+    "word_count",
+    "retracted",
+];
+
+/// Set a single item attribute
+///
+/// Storage Type: Attribute - StorageNMap<(CollectionId, Option<ItemId>, Namespace, Key), (Value, Deposit)>
+pub async fn set_item_attribute(
     client: &AssetHubClient,
     keypair: &Keypair,
     collection_id: u32,
-    title: &str,
-    content_hash: &str
-) -> Result<u32, EduNewsError> {
-    // Get the next item ID based on collection's items count
-    let item_id = get_next_item_id(client, collection_id).await?;
+    item_id: u32,
+    key: &str,
+    value: &str
+) -> Result<TxReceipt, EduNewsError> {
+    let attribute_tx = assethub::tx().nfts().set_attribute(
+        collection_id,
+        Some(item_id),
+        AttributeNamespace::CollectionOwner,
+        BoundedVec(key.as_bytes().to_vec()),
+        BoundedVec(value.as_bytes().to_vec())
+    );
+
+    let (_, receipt) = submit_and_watch(client, &attribute_tx, keypair).await?;
 
-    // Mint the NFT
-    let mint_tx = assethub::tx().nfts().mint(
+    Ok(receipt)
+}
+
+/// Mark an item as retracted, with the reason as the `retracted` attribute
+///
+/// The reason must fit pallet_nfts' ValueLimit.
+pub async fn retract_item(
+    client: &AssetHubClient,
+    keypair: &Keypair,
+    collection_id: u32,
+    item_id: u32,
+    reason: &str
+) -> Result<TxReceipt, EduNewsError> {
+    let value_limit = client.constants().at(&assethub::constants().nfts().value_limit())?;
+    if reason.len() > (value_limit as usize) {
+        return Err(EduNewsError::FieldTooLong {
+            field: "retraction reason".to_string(),
+            len: reason.len(),
+            max: value_limit,
+        });
+    }
+
+    let receipt = set_item_attribute(client, keypair, collection_id, item_id, "retracted", reason).await?;
+
+    print_success(&format!("Marked NFT as retracted: collection {}, item {}", collection_id, item_id));
+    Ok(receipt)
+}
+
+/// Read the article's provenance attributes back from an item
+///
+/// Attributes that were never set are left as `None`.
+pub async fn get_nft_attributes(
+    client: &AssetHubClient,
+    collection_id: u32,
+    item_id: u32
+) -> Result<NftAttributes, EduNewsError> {
+    let mut attributes = NftAttributes {
         collection_id,
         item_id,
-        subxt::utils::MultiAddress::Id(keypair.public_key().into()),
-        None // witness_data
+        ..Default::default()
+    };
+    for key in ATTRIBUTE_KEYS {
+        if let Some(value) = get_nft_attribute(client, collection_id, item_id, key).await? {
+            attributes.set(key, value);
+        }
+    }
+
+    Ok(attributes)
+}
+
+/// Read a single item attribute from the collection owner's namespace
+pub async fn get_nft_attribute(
+    client: &AssetHubClient,
+    collection_id: u32,
+    item_id: u32,
+    key: &str
+) -> Result<Option<String>, EduNewsError> {
+    let attribute_query = assethub::storage().nfts().attribute(
+        collection_id,
+        Some(item_id),
+        AttributeNamespace::CollectionOwner,
+        BoundedVec(key.as_bytes().to_vec())
     );
 
-    let _events = client
-        .tx()
-        .sign_and_submit_then_watch_default(&mint_tx, keypair).await?
-        .wait_for_finalized_success().await?;
+    let attribute = client.storage().at_latest().await?.fetch(&attribute_query).await?;
+
+    Ok(attribute.map(|(value, _deposit)| String::from_utf8_lossy(&value.0).into_owned()))
+}
+
+/// Get the retraction reason of an item, if it was retracted
+pub async fn get_retraction(
+    client: &AssetHubClient,
+    collection_id: u32,
+    item_id: u32
+) -> Result<Option<String>, EduNewsError> {
+    get_nft_attribute(client, collection_id, item_id, "retracted").await
+}
 
-    // Set metadata for the NFT
-    let metadata = format!("{{\"title\":\"{}\",\"content_hash\":\"{}\"}}", title, content_hash);
-    let metadata_bounded = BoundedVec(metadata.into_bytes());
+/// Read and parse the article metadata stored on an item
+///
+/// Storage Type: ItemMetadataOf - StorageDoubleMap<CollectionId, ItemId, ItemMetadata>
+/// Returns `None` when the item has no metadata or it isn't EduNews metadata.
+/// The canonical URL and EduChain genesis that schema version 2 leaves out
+/// of the document are filled in from the item's attributes.
+pub async fn get_nft_metadata(
+    client: &AssetHubClient,
+    collection_id: u32,
+    item_id: u32
+) -> Result<Option<ArticleNftMetadata>, EduNewsError> {
+    let metadata_query = assethub::storage().nfts().item_metadata_of(collection_id, item_id);
 
-    let metadata_tx = assethub::tx().nfts().set_metadata(collection_id, item_id, metadata_bounded);
+    let mut metadata = match client
+        .storage()
+        .at_latest().await?
+        .fetch(&metadata_query).await?
+        .and_then(|item_metadata| ArticleNftMetadata::parse(&item_metadata.data.0))
+    {
+        Some(metadata) => metadata,
+        None => {
+            return Ok(None);
+        }
+    };
+    if metadata.canonical_url.is_none() {
+        metadata.canonical_url = get_nft_attribute(client, collection_id, item_id, "canonical_url").await?;
+    }
+    if metadata.educhain_genesis.is_none() {
+        metadata.educhain_genesis = get_nft_attribute(client, collection_id, item_id, "educhain_genesis").await?;
+    }
+
+    Ok(Some(metadata))
+}
+
+/// Undo a mint whose article never made it onto EduChain
+///
+/// Clears the item attributes and metadata (reclaiming their deposits) and
+/// then burns the item (reclaiming the item deposit). Every step is
+/// attempted even if an earlier one fails, and the report lists exactly
+/// what was undone.
+pub async fn rollback_nft(
+    client: &AssetHubClient,
+    keypair: &Keypair,
+    collection_id: u32,
+    item_id: u32
+) -> RollbackReport {
+    let mut report = RollbackReport {
+        collection_id,
+        item_id,
+        attributes_cleared: Vec::new(),
+        metadata_cleared: None,
+        item_burned: None,
+        errors: Vec::new(),
+    };
+
+    // Only attributes that were actually set need clearing
+    match get_nft_attributes(client, collection_id, item_id).await {
+        Ok(attributes) => {
+            for (key, _) in attributes.entries() {
+                match clear_item_attribute(client, keypair, collection_id, item_id, key).await {
+                    Ok(_) => report.attributes_cleared.push(key.to_string()),
+                    Err(e) => report.errors.push(format!("clear_attribute {}: {}", key, e)),
+                }
+            }
+        }
+        Err(e) => report.errors.push(format!("read attributes: {}", e)),
+    }
+
+    match clear_item_metadata(client, keypair, collection_id, item_id).await {
+        Ok(receipt) => {
+            report.metadata_cleared = Some(receipt);
+        }
+        // Nothing to clear if the failure happened before metadata was set
+        Err(EduNewsError::NftMetadataNotFound) => {}
+        Err(e) => report.errors.push(format!("clear_metadata: {}", e)),
+    }
+
+    match burn_item(client, keypair, collection_id, item_id).await {
+        Ok(receipt) => {
+            report.item_burned = Some(receipt);
+        }
+        Err(e) => report.errors.push(format!("burn: {}", e)),
+    }
+
+    report
+}
+
+/// Clear the metadata of an item, freeing its deposit
+pub async fn clear_item_metadata(
+    client: &AssetHubClient,
+    keypair: &Keypair,
+    collection_id: u32,
+    item_id: u32
+) -> Result<TxReceipt, EduNewsError> {
+    let clear_tx = assethub::tx().nfts().clear_metadata(collection_id, item_id);
+
+    let (_, receipt) = submit_and_watch(client, &clear_tx, keypair).await?;
+
+    print_success(&format!("Cleared metadata of NFT: collection {}, item {}", collection_id, item_id));
+    Ok(receipt)
+}
+
+/// Clear one of the item's attributes, freeing its deposit
+pub async fn clear_item_attribute(
+    client: &AssetHubClient,
+    keypair: &Keypair,
+    collection_id: u32,
+    item_id: u32,
+    key: &str
+) -> Result<TxReceipt, EduNewsError> {
+    let clear_tx = assethub::tx().nfts().clear_attribute(
+        collection_id,
+        Some(item_id),
+        AttributeNamespace::CollectionOwner,
+        BoundedVec(key.as_bytes().to_vec())
+    );
 
-    let _metadata_events = client
-        .tx()
-        .sign_and_submit_then_watch_default(&metadata_tx, keypair).await?
-        .wait_for_finalized_success().await?;
+    let (_, receipt) = submit_and_watch(client, &clear_tx, keypair).await?;
 
-    print_success(&format!("Minted NFT on AssetHub: collection {}, item {}", collection_id, item_id));
-    Ok(item_id)
+    Ok(receipt)
 }
 
-/// Get the next item ID for a collection based on its items count
+/// Burn an item owned by the signer
+pub async fn burn_item(
+    client: &AssetHubClient,
+    keypair: &Keypair,
+    collection_id: u32,
+    item_id: u32
+) -> Result<TxReceipt, EduNewsError> {
+    let burn_tx = assethub::tx().nfts().burn(collection_id, item_id);
+
+    let (_, receipt) = submit_and_watch(client, &burn_tx, keypair).await?;
+
+    print_success(&format!("Burned NFT: collection {}, item {}", collection_id, item_id));
+    Ok(receipt)
+}
+
+/// Estimate the fee of the batch that `create_nft` would submit for a plan
+pub async fn estimate_batch_fee(
+    client: &AssetHubClient,
+    signer: &AccountId32,
+    calls: Vec<RuntimeCall>
+) -> Result<u128, EduNewsError> {
+    estimate_fee(client, &assethub::tx().utility().batch_all(calls), signer).await
+}
+
+/// Estimate the fees of the lock transactions submitted after registration
+pub async fn estimate_lock_fees(
+    client: &AssetHubClient,
+    signer: &AccountId32,
+    collection_id: u32,
+    item_id: u32,
+    lock_properties: Option<(bool, bool)>,
+    lock_transfer: bool
+) -> Result<Vec<PlannedCost>, EduNewsError> {
+    let mut fees = Vec::new();
+    if let Some((lock_metadata, lock_attributes)) = lock_properties {
+        let lock_tx = assethub::tx().nfts().lock_item_properties(collection_id, item_id, lock_metadata, lock_attributes);
+        fees.push(PlannedCost {
+            description: "nfts.lock_item_properties".to_string(),
+            amount: estimate_fee(client, &lock_tx, signer).await?,
+        });
+    }
+    if lock_transfer {
+        let lock_tx = assethub::tx().nfts().lock_item_transfer(collection_id, item_id);
+        fees.push(PlannedCost {
+            description: "nfts.lock_item_transfer".to_string(),
+            amount: estimate_fee(client, &lock_tx, signer).await?,
+        });
+    }
+    Ok(fees)
+}
+
+/// Lock an item's metadata and/or attributes so they can never change again
+pub async fn lock_item_properties(
+    client: &AssetHubClient,
+    keypair: &Keypair,
+    collection_id: u32,
+    item_id: u32,
+    lock_metadata: bool,
+    lock_attributes: bool
+) -> Result<TxReceipt, EduNewsError> {
+    let lock_tx = assethub::tx().nfts().lock_item_properties(collection_id, item_id, lock_metadata, lock_attributes);
+
+    let (_, receipt) = submit_and_watch(client, &lock_tx, keypair).await?;
+
+    print_success(&format!("Locked properties of NFT: collection {}, item {}", collection_id, item_id));
+    Ok(receipt)
+}
+
+/// Make an item non-transferable
+pub async fn lock_item_transfer(
+    client: &AssetHubClient,
+    keypair: &Keypair,
+    collection_id: u32,
+    item_id: u32
+) -> Result<TxReceipt, EduNewsError> {
+    let lock_tx = assethub::tx().nfts().lock_item_transfer(collection_id, item_id);
+
+    let (_, receipt) = submit_and_watch(client, &lock_tx, keypair).await?;
+
+    print_success(&format!("Locked transfer of NFT: collection {}, item {}", collection_id, item_id));
+    Ok(receipt)
+}
+
+/// Read which of an item's settings are locked
+///
+/// Storage Type: ItemConfigOf - StorageDoubleMap<CollectionId, ItemId, ItemConfig>
+/// Item settings are stored as bit flags that mark *disabled* settings:
+/// 1 = Transferable, 2 = UnlockedMetadata, 4 = UnlockedAttributes.
+pub async fn get_item_lock_state(
+    client: &AssetHubClient,
+    collection_id: u32,
+    item_id: u32
+) -> Result<Option<LockState>, EduNewsError> {
+    let config_query = assethub::storage().nfts().item_config_of(collection_id, item_id);
+
+    let item_config = client.storage().at_latest().await?.fetch(&config_query).await?;
+
+    Ok(
+        item_config.map(|config| LockState {
+            transfer_locked: config.settings.0 & 1 != 0,
+            metadata_locked: config.settings.0 & 2 != 0,
+            attributes_locked: config.settings.0 & 4 != 0,
+        })
+    )
+}
+
+/// Transfer an item owned by the signer to another account
+pub async fn transfer_item(
+    client: &AssetHubClient,
+    keypair: &Keypair,
+    collection_id: u32,
+    item_id: u32,
+    dest: AccountId32
+) -> Result<TxReceipt, EduNewsError> {
+    let transfer_tx = assethub::tx().nfts().transfer(collection_id, item_id, subxt::utils::MultiAddress::Id(dest));
+
+    let (_, receipt) = submit_and_watch(client, &transfer_tx, keypair).await?;
+
+    print_success(&format!("Transferred NFT: collection {}, item {}", collection_id, item_id));
+    Ok(receipt)
+}
+
+/// Get the next free item ID for a collection, starting at `start_from`
+///
+/// The collection's `items` count is only a hint: it drops when items are
+/// burned, so it can point at an ID that is still taken. Each candidate is
+/// probed in `Item` and `ItemConfigOf` storage (the config outlives the burn
+/// of a locked item) until an unused one is found.
+///
+/// Burning an unlocked item removes both entries, but keeps its attributes
+/// and the EduChain `RootByItem` link to the old article. IDs with a
+/// `content_hash` or `retracted` attribute, or with an EduChain record, are
+/// skipped too, so a new article never inherits another one's provenance.
 pub async fn get_next_item_id(
     client: &AssetHubClient,
-    collection_id: u32
+    educhain_client: &EduChainClient,
+    collection_id: u32,
+    start_from: u32
 ) -> Result<u32, EduNewsError> {
+    let storage = client.storage().at_latest().await?;
+
     let collection_query = assethub::storage().nfts().collection(collection_id);
-    
-    if let Some(collection_details) = client
-        .storage()
-        .at_latest().await?
+    let collection_details = storage
         .fetch(&collection_query).await?
-    {
-        Ok(collection_details.items)
-    } else {
-        Err(EduNewsError::CollectionNotFound { collection_id })
+        .ok_or(EduNewsError::CollectionNotFound { collection_id })?;
+
+    let mut item_id = collection_details.items.max(start_from);
+    loop {
+        let item_query = assethub::storage().nfts().item(collection_id, item_id);
+        let config_query = assethub::storage().nfts().item_config_of(collection_id, item_id);
+        let attribute_query = |key: &str| {
+            assethub::storage().nfts().attribute(
+                collection_id,
+                Some(item_id),
+                AttributeNamespace::CollectionOwner,
+                BoundedVec(key.as_bytes().to_vec())
+            )
+        };
+
+        let taken =
+            storage.fetch(&item_query).await?.is_some() ||
+            storage.fetch(&config_query).await?.is_some() ||
+            storage.fetch(&attribute_query("content_hash")).await?.is_some() ||
+            storage.fetch(&attribute_query("retracted")).await?.is_some() ||
+            check_article_exists(educhain_client, collection_id, item_id).await?;
+        if !taken {
+            return Ok(item_id);
+        }
+        item_id = item_id.checked_add(1).ok_or(EduNewsError::NftMaxSupplyReached)?;
     }
 }
 
@@ -200,3 +1049,35 @@ pub async fn check_nft_exists(
 
     Ok(item.is_some())
 }
+
+/// Get the current owner of an NFT, if the item exists
+///
+/// Storage Type: Item - StorageDoubleMap<CollectionId, ItemId, ItemDetails>
+pub async fn get_nft_owner(
+    client: &AssetHubClient,
+    collection_id: u32,
+    item_id: u32
+) -> Result<Option<String>, EduNewsError> {
+    let storage_query = assethub::storage().nfts().item(collection_id, item_id);
+
+    let item = client.storage().at_latest().await?.fetch(&storage_query).await?;
+
+    Ok(item.map(|details| details.owner.to_string()))
+}
+
+/// Get the free balance of an account on AssetHub (0 if the account doesn't exist)
+pub async fn get_assethub_free_balance(
+    client: &AssetHubClient,
+    account: &AccountId32
+) -> Result<u128, EduNewsError> {
+    let account_query = assethub::storage().system().account(account.clone());
+
+    let account_info = client.storage().at_latest().await?.fetch(&account_query).await?;
+
+    Ok(account_info.map_or(0, |info| info.data.free))
+}
+
+/// Get the existential deposit of AssetHub's native token
+pub fn get_assethub_existential_deposit(client: &AssetHubClient) -> Result<u128, EduNewsError> {
+    Ok(client.constants().at(&assethub::constants().balances().existential_deposit())?)
+}
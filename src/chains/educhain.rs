@@ -1,16 +1,20 @@
 use std::str::FromStr;
 
-use subxt::utils::H256;
+use subxt::backend::legacy::LegacyRpcMethods;
+use subxt::backend::rpc::RpcClient;
+use subxt::utils::{ AccountId32, H256 };
+use subxt::tx::Payload;
 use subxt::{ OnlineClient, PolkadotConfig };
 use subxt_signer::sr25519::{ Keypair, Signature };
 
 use crate::config::educhain::runtime_types::bounded_collections::bounded_vec::BoundedVec;
-use crate::config::educhain::runtime_types::pallet_news::pallet::HashAlgo;
+use crate::config::educhain::runtime_types::pallet_news::pallet::{ ArticleRecord, HashAlgo };
 use crate::config::educhain::runtime_types::sp_runtime::MultiSignature;
 use crate::config::{ educhain, get_rpc_urls };
+use crate::chains::{ estimate_fee, submit_and_watch };
 use crate::utils::print_success;
 use crate::error::EduNewsError;
-use crate::types::{ Network, Article };
+use crate::types::{ Network, Article, ArticleHistory, ArticleVersion, ContentMatch, TxReceipt, VersionInfo };
 
 pub type EduChainClient = OnlineClient<PolkadotConfig>;
 
@@ -22,11 +26,15 @@ pub async fn create_educhain_client(network: Network) -> Result<EduChainClient,
         ::from_url(&rpc_url).await
         .map_err(|e| EduNewsError::ChainConnection {
             chain: "EduChain".to_string(),
-            source: e,
+            source: Box::new(e),
         })
 }
 
 /// Register article on EduChain with confirmed collection and item IDs
+///
+/// Returns the transaction receipt and the publisher reported by the
+/// `ArticleRecorded` event.
+#[allow(clippy::too_many_arguments)]
 pub async fn register_article(
     client: &EduChainClient,
     keypair: &Keypair,
@@ -37,16 +45,193 @@ pub async fn register_article(
     url: &str,
     content_hash: &str,
     word_count: u32
-) -> Result<String, EduNewsError> {
+) -> Result<(TxReceipt, String), EduNewsError> {
+    let tx = record_article_tx(collection_id, signature, item_id, title, url, content_hash, word_count)?;
+
+    let (events, receipt) = submit_and_watch(client, &tx, keypair).await?;
+
+    // The pallet confirms the anchor and its publisher in the ArticleRecorded event
+    let recorded = events
+        .find_first::<educhain::news::events::ArticleRecorded>()?
+        .ok_or_else(|| EduNewsError::MissingEvent {
+            chain: "EduChain".to_string(),
+            event: "News.ArticleRecorded".to_string(),
+        })?;
+
+    print_success(
+        &format!(
+            "Article recorded on EduChain in block #{} ({})",
+            receipt.block_number,
+            receipt.extrinsic_hash
+        )
+    );
+    Ok((receipt, recorded.publisher.to_string()))
+}
+
+/// Find the receipt of the `record_article` that recorded an anchor
+///
+/// Recovers a registration whose EduChain step finalized but was never
+/// journaled. A fresh anchor's `last_updated_at` is the block it was
+/// recorded in, so only that block is searched for the `ArticleRecorded`
+/// event of `content_hash`.
+pub async fn find_record_receipt(
+    client: &EduChainClient,
+    network: Network,
+    content_hash: H256,
+    block_number: u32
+) -> Result<Option<TxReceipt>, EduNewsError> {
+    // Blocks are fetched by hash, so resolve the number through the legacy RPC
+    let (rpc_url, _, _) = get_rpc_urls(network);
+    let rpc_client = RpcClient::from_url(&rpc_url).await.map_err(subxt::Error::from)?;
+    let rpc = LegacyRpcMethods::<PolkadotConfig>::new(rpc_client);
+    let block_hash = match rpc.chain_get_block_hash(Some(block_number.into())).await.map_err(subxt::Error::from)? {
+        Some(hash) => hash,
+        None => {
+            return Ok(None);
+        }
+    };
+
+    let block = client.blocks().at(block_hash).await?;
+    for extrinsic in block.extrinsics().await?.iter() {
+        let recorded = extrinsic.events().await?.find_first::<educhain::news::events::ArticleRecorded>()?;
+        if recorded.is_some_and(|recorded| recorded.content_hash == content_hash) {
+            return Ok(
+                Some(TxReceipt {
+                    extrinsic_hash: format!("0x{}", hex::encode(extrinsic.hash().0)),
+                    block_hash: format!("0x{}", hex::encode(block_hash.0)),
+                    block_number: block_number.into(),
+                    extrinsic_index: extrinsic.index(),
+                })
+            );
+        }
+    }
+
+    Ok(None)
+}
+
+/// Build the `record_article` call submitted by `register_article`
+fn record_article_tx(
+    collection_id: u32,
+    signature: Signature,
+    item_id: u32,
+    title: &str,
+    url: &str,
+    content_hash: &str,
+    word_count: u32
+) -> Result<impl Payload, EduNewsError> {
+    Ok(
+        educhain
+            ::tx()
+            .news()
+            .record_article(
+                parse_content_hash(content_hash)?,
+                collection_id.into(),
+                item_id.into(),
+                BoundedVec(title.as_bytes().to_vec()),
+                BoundedVec(url.as_bytes().to_vec()),
+                MultiSignature::Sr25519(signature.0),
+                HashAlgo::Blake2b256,
+                word_count
+            )
+    )
+}
+
+/// Estimate the fee of the `record_article` call for a registration
+///
+/// A zeroed content signature stands in for the real one; it has the same
+/// length, so the fee is the same.
+#[allow(clippy::too_many_arguments)]
+pub async fn estimate_record_fee(
+    client: &EduChainClient,
+    signer: &AccountId32,
+    collection_id: u32,
+    item_id: u32,
+    title: &str,
+    url: &str,
+    content_hash: &str,
+    word_count: u32
+) -> Result<u128, EduNewsError> {
+    let tx = record_article_tx(collection_id, Signature([0u8; 64]), item_id, title, url, content_hash, word_count)?;
+    estimate_fee(client, &tx, signer).await
+}
+
+/// Pre-flight checks for a registration, run before any transaction is sent
+///
+/// `record_article` would reject these cases anyway, but only after the NFT
+/// and its deposits are already on AssetHub. Checking first avoids that:
+/// 1. ArticleByHash must not already hold the content hash
+/// 2. The publisher's ArticlesByPublisher list must have room left
+/// 3. Title and URL must fit the pallet's MaxTitleLen / MaxUrlLen constants
+pub async fn validate_registration(
+    client: &EduChainClient,
+    publisher: &AccountId32,
+    content_hash: &str,
+    title: &str,
+    url: &str
+) -> Result<(), EduNewsError> {
+    // Constants are read from the metadata, so they always match the runtime
+    let max_title_len = client.constants().at(&educhain::constants().news().max_title_len())?;
+    let max_url_len = client.constants().at(&educhain::constants().news().max_url_len())?;
+    let max_articles = client
+        .constants()
+        .at(&educhain::constants().news().max_articles_per_publisher())?;
+
+    check_field_length("title", title, max_title_len)?;
+    check_field_length("URL", url, max_url_len)?;
+
+    let storage = client.storage().at_latest().await?;
+
+    let article_query = educhain::storage().news().article_by_hash(parse_content_hash(content_hash)?);
+    if storage.fetch(&article_query).await?.is_some() {
+        return Err(EduNewsError::ArticleAlreadyExists);
+    }
+
+    let publisher_query = educhain::storage().news().articles_by_publisher(publisher.clone());
+    let published = storage.fetch(&publisher_query).await?.map(|hashes| hashes.0.len()).unwrap_or(0);
+    if published >= (max_articles as usize) {
+        return Err(EduNewsError::PublisherArticleListFull);
+    }
+
+    Ok(())
+}
+
+/// Check a string field against a pallet length limit (in bytes)
+fn check_field_length(field: &str, value: &str, max: u32) -> Result<(), EduNewsError> {
+    if value.len() > (max as usize) {
+        return Err(EduNewsError::FieldTooLong {
+            field: field.to_string(),
+            len: value.len(),
+            max,
+        });
+    }
+    Ok(())
+}
+
+/// Publish a new version of an existing article on EduChain
+///
+/// `update_article` appends `new_hash` to the anchor's history and bumps
+/// `ArticleUpdateCount`. Only the original publisher may call it, and
+/// `old_hash` must be the version being superseded.
+///
+/// Returns the new version's number, counted like `history` numbers them
+/// (the anchor is version 1), along with the transaction receipt.
+#[allow(clippy::too_many_arguments)]
+pub async fn update_article(
+    client: &EduChainClient,
+    keypair: &Keypair,
+    old_hash: &str,
+    new_hash: &str,
+    signature: Signature,
+    title: &str,
+    url: &str,
+    word_count: u32
+) -> Result<(u32, TxReceipt), EduNewsError> {
     let tx = educhain
         ::tx()
         .news()
-        .record_article(
-            H256::from_str(content_hash).map_err(|_| EduNewsError::InvalidContentHash {
-                hash: content_hash.to_string(),
-            })?,
-            collection_id.into(),
-            item_id.into(),
+        .update_article(
+            parse_content_hash(old_hash)?,
+            parse_content_hash(new_hash)?,
             BoundedVec(title.as_bytes().to_vec()),
             BoundedVec(url.as_bytes().to_vec()),
             MultiSignature::Sr25519(signature.0),
@@ -54,16 +239,197 @@ pub async fn register_article(
             word_count
         );
 
-    let _events = client
-        .tx()
-        .sign_and_submit_then_watch_default(&tx, keypair).await?
-        .wait_for_finalized_success().await?;
+    let (events, receipt) = submit_and_watch(client, &tx, keypair).await?;
+
+    // The new version's position in HistoryByAnchor, where the anchor is at 0
+    let extended = events
+        .find_first::<educhain::news::events::ArticleHistoryExtended>()?
+        .ok_or_else(|| EduNewsError::MissingEvent {
+            chain: "EduChain".to_string(),
+            event: "News.ArticleHistoryExtended".to_string(),
+        })?;
+
+    print_success(&format!("Article updated on EduChain: {} -> {}", old_hash, new_hash));
+    Ok((extended.index + 1, receipt))
+}
+
+/// Get the ordered version hashes for an anchor
+///
+/// Storage Type: HistoryByAnchor - StorageMap<AnchorHash, BoundedVec<ContentHash>>
+/// The history is append-only and starts with the anchor itself, so the last
+/// entry is always the latest published version.
+pub async fn get_version_hashes(
+    client: &EduChainClient,
+    anchor: H256
+) -> Result<Vec<H256>, EduNewsError> {
+    let history_query = educhain::storage().news().history_by_anchor(anchor);
+
+    let history = client.storage().at_latest().await?.fetch(&history_query).await?;
+
+    // Articles without recorded history have only ever had their anchor version
+    Ok(history.map(|h| h.0).unwrap_or_else(|| vec![anchor]))
+}
+
+/// Get the most recent version hash for an anchor
+pub async fn get_latest_version_hash(
+    client: &EduChainClient,
+    anchor: H256
+) -> Result<H256, EduNewsError> {
+    let history = get_version_hashes(client, anchor).await?;
+
+    Ok(history.last().copied().unwrap_or(anchor))
+}
+
+/// Compare a locally computed content hash against an article's lineage
+///
+/// The hash must have its own ArticleByHash record and appear in the
+/// anchor's history to count as a match.
+pub async fn match_content(
+    client: &EduChainClient,
+    anchor: H256,
+    content_hash: H256
+) -> Result<ContentMatch, EduNewsError> {
+    let article_query = educhain::storage().news().article_by_hash(content_hash);
+
+    if client.storage().at_latest().await?.fetch(&article_query).await?.is_none() {
+        return Ok(ContentMatch::NoMatch);
+    }
+
+    let history = get_version_hashes(client, anchor).await?;
+
+    let content_match = match history.iter().position(|hash| *hash == content_hash) {
+        Some(position) if position + 1 == history.len() => ContentMatch::MatchLatest,
+        Some(_) => ContentMatch::MatchOlderVersion,
+        None => ContentMatch::NoMatch, // Registered, but as part of another article
+    };
+
+    Ok(content_match)
+}
+
+/// Get the anchor hash linked to an NFT
+///
+/// Storage Type: RootByItem - StorageDoubleMap<CollectionId, ItemId, AnchorHash>
+pub async fn get_anchor_by_ids(
+    client: &EduChainClient,
+    collection_id: u32,
+    item_id: u32
+) -> Result<Option<H256>, EduNewsError> {
+    let root_query = educhain::storage().news().root_by_item(collection_id.into(), item_id.into());
+
+    Ok(client.storage().at_latest().await?.fetch(&root_query).await?)
+}
+
+/// Resolve any version hash to the anchor of its lineage
+///
+/// Storage Type: AnchorOf - StorageMap<ContentHash, AnchorHash>
+/// Anchors are looked up through ArticleByHash as a fallback, since the
+/// first version of an article is its own anchor.
+pub async fn resolve_anchor(
+    client: &EduChainClient,
+    content_hash: H256
+) -> Result<Option<H256>, EduNewsError> {
+    let anchor_query = educhain::storage().news().anchor_of(content_hash);
+
+    if let Some(anchor) = client.storage().at_latest().await?.fetch(&anchor_query).await? {
+        return Ok(Some(anchor));
+    }
+
+    let article_query = educhain::storage().news().article_by_hash(content_hash);
+    let record = client.storage().at_latest().await?.fetch(&article_query).await?;
+
+    Ok(record.map(|_| content_hash))
+}
+
+/// Retrieve the full version lineage of an article
+///
+/// 1. HistoryByAnchor gives the ordered list of version hashes (anchor first)
+/// 2. ArticleByHash gives the record stored for each version
+///
+/// Versions are numbered by their position in the history, so the anchor is
+/// version 1.
+pub async fn get_article_history(
+    client: &EduChainClient,
+    anchor: H256
+) -> Result<Option<ArticleHistory>, EduNewsError> {
+    let storage = client.storage().at_latest().await?;
+
+    // The anchor record carries the NFT link shared by every version
+    let anchor_query = educhain::storage().news().article_by_hash(anchor);
+    let anchor_record = match storage.fetch(&anchor_query).await? {
+        Some(record) => record,
+        None => {
+            return Ok(None);
+        } // Not an anchor we know about
+    };
+
+    let history_query = educhain::storage().news().history_by_anchor(anchor);
+    let version_hashes = match storage.fetch(&history_query).await? {
+        Some(history) => history.0,
+        None => vec![anchor], // Only the anchor version exists
+    };
 
-    // Generate article ID from transaction data
-    let article_id = format!("article_{}", &content_hash[0..8]);
+    let mut versions = Vec::new();
+    for (index, version_hash) in version_hashes.into_iter().enumerate() {
+        let article_query = educhain::storage().news().article_by_hash(version_hash);
 
-    print_success(&format!("Article registration simulated on EduChain: {} (ready for real EduChain pallet implementation)", article_id));
-    Ok(article_id)
+        if let Some(record) = storage.fetch(&article_query).await? {
+            versions.push(ArticleVersion {
+                version: (index as u32) + 1,
+                content_hash: format!("0x{}", hex::encode(version_hash.0)),
+                title: String::from_utf8_lossy(&record.title.0).to_string(),
+                url: String::from_utf8_lossy(&record.canonical_url.0).to_string(),
+                publisher: record.publisher.to_string(),
+                last_updated_at: record.last_updated_at,
+            });
+        }
+    }
+
+    Ok(
+        Some(ArticleHistory {
+            anchor: format!("0x{}", hex::encode(anchor.0)),
+            collection_id: anchor_record.collection_id as u32,
+            item_id: anchor_record.item_id as u32,
+            versions,
+        })
+    )
+}
+
+/// Locate a version hash within its article's lineage
+///
+/// Resolves the anchor through AnchorOf, then finds the hash's position in
+/// HistoryByAnchor. A hash is superseded when a later version exists.
+pub async fn get_version_info(
+    client: &EduChainClient,
+    content_hash: H256
+) -> Result<Option<VersionInfo>, EduNewsError> {
+    let anchor = match resolve_anchor(client, content_hash).await? {
+        Some(anchor) => anchor,
+        None => {
+            return Ok(None);
+        } // Unknown hash
+    };
+
+    let history = get_version_hashes(client, anchor).await?;
+
+    let position = history.iter().position(|hash| *hash == content_hash).unwrap_or(0);
+    let latest = history.last().copied().unwrap_or(anchor);
+
+    Ok(
+        Some(VersionInfo {
+            anchor: format!("0x{}", hex::encode(anchor.0)),
+            latest_hash: format!("0x{}", hex::encode(latest.0)),
+            version: (position as u32) + 1,
+            total_versions: history.len() as u32,
+            is_latest: latest == content_hash,
+        })
+    )
+}
+
+/// Parse a `0x`-prefixed content hash string into an H256
+pub fn parse_content_hash(content_hash: &str) -> Result<H256, EduNewsError> {
+    H256::from_str(content_hash).map_err(|_| EduNewsError::InvalidContentHash {
+        hash: content_hash.to_string(),
+    })
 }
 
 /// Retrieve specific article by collection_id and item_id using two-step storage lookup
@@ -100,8 +466,45 @@ pub async fn get_article_by_ids(
     };
 
     // Step 3: Convert Substrate types to our display format
-    // Note: BoundedVec.0 accesses the inner Vec<u8> for title and URL
-    let article = Article {
+    Ok(Some(article_from_record(content_hash, article_record)))
+}
+
+/// Retrieve a specific article version directly by its content hash
+///
+/// Storage Type: ArticleByHash - StorageMap<ContentHash, ArticleRecord>
+/// Every version of an article (anchor and updates) has its own record.
+pub async fn get_article_by_hash(
+    client: &EduChainClient,
+    content_hash: H256
+) -> Result<Option<Article>, EduNewsError> {
+    let article_query = educhain::storage().news().article_by_hash(content_hash);
+
+    let article = client
+        .storage()
+        .at_latest().await?
+        .fetch(&article_query).await?
+        .map(|record| article_from_record(content_hash, record));
+
+    Ok(article)
+}
+
+/// Convert an on-chain ArticleRecord into our display format
+///
+/// Note: BoundedVec.0 accesses the inner Vec<u8> for title and URL
+fn article_from_record(content_hash: H256, article_record: ArticleRecord) -> Article {
+    // The stored MultiSignature tells us which scheme the publisher signed with
+    let (signature_type, signature) = match &article_record.signature {
+        MultiSignature::Ed25519(sig) => ("ed25519", &sig[..]),
+        MultiSignature::Sr25519(sig) => ("sr25519", &sig[..]),
+        MultiSignature::Ecdsa(sig) => ("ecdsa", &sig[..]),
+    };
+
+    let hash_algo = match article_record.hash_algo {
+        HashAlgo::Sha256 => "sha256",
+        HashAlgo::Blake2b256 => "blake2b256",
+    };
+
+    Article {
         collection_id: article_record.collection_id as u32,
         item_id: article_record.item_id as u32,
         title: String::from_utf8_lossy(&article_record.title.0).to_string(),
@@ -109,11 +512,16 @@ pub async fn get_article_by_ids(
         content_hash: format!("0x{}", hex::encode(content_hash.0)),
         publisher: article_record.publisher.to_string(), // AccountId32 -> String
         timestamp: article_record.last_updated_at as u64, // BlockNumber -> u64
+        hash_algo: hash_algo.to_string(),
+        signature_type: signature_type.to_string(),
+        signature: format!("0x{}", hex::encode(signature)),
         verified_nft: false, // Will be checked by caller against AssetHub
         verified_identity: false, // Will be checked by caller against PeopleHub
-    };
-
-    Ok(Some(article))
+        version: None, // Will be resolved by caller against the anchor history
+        nft_metadata: None, // Will be read by caller from AssetHub
+        nft_owner: None, // Will be read by caller from AssetHub
+        retracted: None, // Will be read by caller from AssetHub
+    }
 }
 
 /// Get articles by publisher address
@@ -148,19 +556,7 @@ pub async fn get_articles_by_publisher(
                 .at_latest().await?
                 .fetch(&article_query).await?
         {
-            let article = Article {
-                collection_id: article_record.collection_id as u32,
-                item_id: article_record.item_id as u32,
-                title: String::from_utf8_lossy(&article_record.title.0).to_string(),
-                url: String::from_utf8_lossy(&article_record.canonical_url.0).to_string(),
-                content_hash: format!("0x{}", hex::encode(content_hash.0)),
-                publisher: article_record.publisher.to_string(),
-                timestamp: article_record.last_updated_at as u64,
-                verified_nft: false, // Will be updated by caller if needed
-                verified_identity: false, // Will be updated by caller if needed
-            };
-
-            articles.push(article);
+            articles.push(article_from_record(content_hash, article_record));
         }
     }
 
@@ -198,3 +594,20 @@ pub async fn check_article_exists(
     // Return true if the mapping exists (Some), false if not (None)
     Ok(result.is_some())
 }
+
+/// Get the free balance of an account on EduChain (0 if the account doesn't exist)
+pub async fn get_educhain_free_balance(
+    client: &EduChainClient,
+    account: &AccountId32
+) -> Result<u128, EduNewsError> {
+    let account_query = educhain::storage().system().account(account.clone());
+
+    let account_info = client.storage().at_latest().await?.fetch(&account_query).await?;
+
+    Ok(account_info.map_or(0, |info| info.data.free))
+}
+
+/// Get the existential deposit of EduChain's native token
+pub fn get_educhain_existential_deposit(client: &EduChainClient) -> Result<u128, EduNewsError> {
+    Ok(client.constants().at(&educhain::constants().balances().existential_deposit())?)
+}
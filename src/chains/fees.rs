@@ -0,0 +1,21 @@
+use subxt::tx::Payload;
+use subxt::utils::{ AccountId32, MultiSignature };
+use subxt::{ OnlineClient, PolkadotConfig };
+
+use crate::error::EduNewsError;
+
+/// Estimate the partial fee of a call through `TransactionPaymentApi::query_info`
+///
+/// The extrinsic is built for `signer` (with its current nonce) but carries a
+/// dummy signature: fees depend on the extrinsic's weight and length, not on
+/// the signature bytes, so nothing is ever signed or submitted.
+pub async fn estimate_fee<Call: Payload>(
+    client: &OnlineClient<PolkadotConfig>,
+    call: &Call,
+    signer: &AccountId32
+) -> Result<u128, EduNewsError> {
+    let mut partial = client.tx().create_partial(call, signer, Default::default()).await?;
+    let tx = partial.sign_with_account_and_signature(signer, &MultiSignature::Sr25519([0u8; 64]));
+
+    Ok(tx.partial_fee_estimate().await?)
+}
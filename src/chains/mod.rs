@@ -1,7 +1,11 @@
 pub mod educhain;
 pub mod assethub;
 pub mod peoplehub;
+pub mod receipt;
+pub mod fees;
 
 pub use educhain::*;
 pub use assethub::*;
-pub use peoplehub::*;
\ No newline at end of file
+pub use peoplehub::*;
+pub use receipt::*;
+pub use fees::*;
\ No newline at end of file
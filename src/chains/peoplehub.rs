@@ -1,15 +1,15 @@
-/// PeopleHub Identity Verification
-/// 
-/// This module demonstrates how to interact with Polkadot's Identity pallet
-/// using Subxt to verify publisher identities. Key concepts:
-/// 
-/// 1. **Identity Storage Queries**: Query the identity_of storage map
-/// 2. **Identity Data Handling**: Work with the Identity pallet's Data enum
-/// 3. **Verification Logic**: Simple existence check for identity records
-/// 4. **Cross-chain Integration**: Link identity verification with article publishing
-/// 
-/// The Identity pallet is crucial for establishing trust in decentralized systems
-/// by allowing accounts to associate verified information with their addresses.
+//! PeopleHub Identity Verification
+//! 
+//! This module demonstrates how to interact with Polkadot's Identity pallet
+//! using Subxt to verify publisher identities. Key concepts:
+//! 
+//! 1. **Identity Storage Queries**: Query the identity_of storage map
+//! 2. **Identity Data Handling**: Work with the Identity pallet's Data enum
+//! 3. **Verification Logic**: Simple existence check for identity records
+//! 4. **Cross-chain Integration**: Link identity verification with article publishing
+//! 
+//! The Identity pallet is crucial for establishing trust in decentralized systems
+//! by allowing accounts to associate verified information with their addresses.
 
 use subxt::{OnlineClient, PolkadotConfig};
 
@@ -27,7 +27,7 @@ pub async fn create_peoplehub_client(network: Network) -> Result<PeopleHubClient
         .await
         .map_err(|e| EduNewsError::ChainConnection {
             chain: "PeopleHub".to_string(),
-            source: e,
+            source: Box::new(e),
         })
 }
 
@@ -0,0 +1,47 @@
+use subxt::blocks::ExtrinsicEvents;
+use subxt::tx::Payload;
+use subxt::utils::H256;
+use subxt::{ OnlineClient, PolkadotConfig };
+use subxt_signer::sr25519::Keypair;
+
+use crate::error::EduNewsError;
+use crate::types::TxReceipt;
+
+/// Sign and submit a call, wait for it to be finalized and successful, and
+/// return its events together with its receipt
+///
+/// Dispatch failures come back as typed `EduNewsError` variants.
+pub async fn submit_and_watch<Call: Payload>(
+    client: &OnlineClient<PolkadotConfig>,
+    call: &Call,
+    keypair: &Keypair
+) -> Result<(ExtrinsicEvents<PolkadotConfig>, TxReceipt), EduNewsError> {
+    let in_block = client
+        .tx()
+        .sign_and_submit_then_watch_default(call, keypair).await?
+        .wait_for_finalized().await?;
+    let events = in_block.wait_for_success().await?;
+    let receipt = tx_receipt(client, in_block.block_hash(), &events).await?;
+
+    Ok((events, receipt))
+}
+
+/// Build a transaction receipt from the events of a finalized extrinsic
+///
+/// `ExtrinsicEvents` already knows the extrinsic hash and its index, and
+/// `block_hash` comes from the finalized `TxInBlock`; only the block number
+/// needs one extra lookup.
+async fn tx_receipt(
+    client: &OnlineClient<PolkadotConfig>,
+    block_hash: H256,
+    events: &ExtrinsicEvents<PolkadotConfig>
+) -> Result<TxReceipt, EduNewsError> {
+    let block = client.blocks().at(block_hash).await?;
+
+    Ok(TxReceipt {
+        extrinsic_hash: format!("0x{}", hex::encode(events.extrinsic_hash().0)),
+        block_hash: format!("0x{}", hex::encode(block_hash.0)),
+        block_number: block.number().into(),
+        extrinsic_index: events.extrinsic_index(),
+    })
+}
@@ -0,0 +1,182 @@
+use std::fs;
+
+use crate::chains::{
+    check_collection_owner,
+    create_assethub_client,
+    get_collection_info,
+    set_collection_max_supply,
+    set_collection_metadata,
+    set_default_transferable,
+};
+use crate::commands::{ CollectionArgs, ProfileArgs };
+use crate::error::EduNewsError;
+use crate::types::{ Network, ProfileOverlay, PublicationProfile, PUBLICATION_SCHEMA_VERSION };
+use crate::utils::{ create_keypair_from_mnemonic, format_output, print_info, print_success };
+
+impl CollectionArgs {
+    /// Show a collection's publication profile, or update it
+    ///
+    /// Profile fields are merged over the current collection metadata, so
+    /// only the given fields change. Max supply and transferability are
+    /// collection config changes with their own transactions.
+    pub async fn execute(&self, network: Network, json_output: bool) -> Result<(), EduNewsError> {
+        let assethub_client = create_assethub_client(network).await?;
+
+        let info = get_collection_info(&assethub_client, self.collection_id).await?
+            .ok_or(EduNewsError::CollectionNotFound { collection_id: self.collection_id })?;
+
+        if self.profile.is_empty() {
+            let output = format_output(&info, json_output)?;
+            println!("{}", output);
+            return Ok(());
+        }
+
+        let mnemonic = self.mnemonic.as_deref().ok_or(EduNewsError::MnemonicRequired)?;
+        let keypair = create_keypair_from_mnemonic(mnemonic)?;
+        check_collection_owner(&assethub_client, &keypair, self.collection_id).await?;
+
+        if !json_output {
+            print_info(&format!("Updating collection {}", self.collection_id));
+        }
+
+        let base = match &info.profile {
+            Some(profile) => profile.clone(),
+            None => PublicationProfile {
+                name: info.publication_name().unwrap_or_default().to_string(),
+                ..Default::default()
+            },
+        };
+        let profile = self.profile.resolve(base)?;
+
+        if self.profile.changes_metadata() {
+            set_collection_metadata(&assethub_client, &keypair, self.collection_id, &profile).await?;
+        }
+        // The profile file can set these too, so read them from the resolved profile
+        if let Some(max_supply) = profile.max_supply {
+            set_collection_max_supply(&assethub_client, &keypair, self.collection_id, max_supply).await?;
+        }
+        if let Some(transferable) = profile.transferable {
+            set_default_transferable(&assethub_client, &keypair, self.collection_id, transferable).await?;
+        }
+
+        // Show the collection as it is on chain now
+        let info = get_collection_info(&assethub_client, self.collection_id).await?
+            .ok_or(EduNewsError::CollectionNotFound { collection_id: self.collection_id })?;
+
+        let output = format_output(&info, json_output)?;
+        if json_output {
+            println!("{}", output);
+        } else {
+            print_success("Collection updated successfully!");
+            println!("{}", output);
+        }
+
+        Ok(())
+    }
+}
+
+impl ProfileArgs {
+    /// Whether no profile file or field was given
+    ///
+    /// A profile file counts even when it only sets `max_supply` or
+    /// `transferable`, since those are taken from the resolved profile.
+    pub fn is_empty(&self) -> bool {
+        !self.changes_metadata() && self.max_supply.is_none() && self.transferable.is_none()
+    }
+
+    /// Whether any field stored in the collection metadata was given
+    pub fn changes_metadata(&self) -> bool {
+        self.profile_file.is_some() ||
+            self.publication_name.is_some() ||
+            self.website.is_some() ||
+            self.logo_hash.is_some() ||
+            self.description.is_some()
+    }
+
+    /// Apply the profile file over `base`, then the flags over both
+    ///
+    /// Fields the file leaves out keep their value from `base`.
+    pub fn resolve(&self, base: PublicationProfile) -> Result<PublicationProfile, EduNewsError> {
+        let mut profile = base;
+
+        if let Some(path) = &self.profile_file {
+            let data = fs::read_to_string(path).map_err(|e| EduNewsError::FileRead {
+                path: path.clone(),
+                source: e,
+            })?;
+            serde_json::from_str::<ProfileOverlay>(&data)?.apply(&mut profile);
+        }
+
+        ProfileOverlay {
+            name: self.publication_name.clone(),
+            website: self.website.clone(),
+            logo_hash: self.logo_hash.clone(),
+            description: self.description.clone(),
+            max_supply: self.max_supply,
+            transferable: self.transferable,
+        }.apply(&mut profile);
+
+        profile.schema_version = PUBLICATION_SCHEMA_VERSION;
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile_args(profile_file: Option<std::path::PathBuf>) -> ProfileArgs {
+        ProfileArgs {
+            profile_file,
+            publication_name: None,
+            website: None,
+            logo_hash: None,
+            description: None,
+            max_supply: None,
+            transferable: None,
+        }
+    }
+
+    fn current_profile() -> PublicationProfile {
+        PublicationProfile {
+            schema_version: PUBLICATION_SCHEMA_VERSION,
+            name: "The Daily Chain".to_string(),
+            website: Some("https://daily.example".to_string()),
+            logo_hash: Some(format!("0x{}", "11".repeat(32))),
+            ..Default::default()
+        }
+    }
+
+    fn write_profile_file(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("edunews-profile-{}-{}.json", std::process::id(), name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn profile_file_only_changes_the_fields_it_names() {
+        let path = write_profile_file("description", r#"{"description":"Independent news since 2024"}"#);
+        let profile = profile_args(Some(path.clone())).resolve(current_profile()).unwrap();
+        let _ = fs::remove_file(path);
+
+        assert_eq!(profile.name, "The Daily Chain");
+        assert_eq!(profile.website.as_deref(), Some("https://daily.example"));
+        assert_eq!(profile.logo_hash, current_profile().logo_hash);
+        assert_eq!(profile.description.as_deref(), Some("Independent news since 2024"));
+    }
+
+    #[test]
+    fn flags_override_the_profile_file() {
+        let path = write_profile_file("flags", r#"{"n":"Weekly Chain","max_supply":100,"transferable":false}"#);
+        let mut args = profile_args(Some(path.clone()));
+        args.publication_name = Some("Evening Chain".to_string());
+        args.transferable = Some(true);
+        let profile = args.resolve(current_profile()).unwrap();
+        let _ = fs::remove_file(path);
+
+        assert_eq!(profile.name, "Evening Chain");
+        assert_eq!(profile.website.as_deref(), Some("https://daily.example"));
+        assert_eq!(profile.max_supply, Some(100));
+        assert_eq!(profile.transferable, Some(true));
+    }
+}
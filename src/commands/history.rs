@@ -0,0 +1,55 @@
+use crate::chains::{
+    create_educhain_client,
+    get_anchor_by_ids,
+    get_article_history,
+    parse_content_hash,
+    resolve_anchor,
+};
+use crate::commands::HistoryArgs;
+use crate::error::EduNewsError;
+use crate::types::Network;
+use crate::utils::{ format_output, print_info };
+
+impl HistoryArgs {
+    /// Show every version of an article
+    ///
+    /// The article can be identified by its NFT ids or by the content hash of
+    /// any version. Either way it is resolved to the anchor, whose history
+    /// lists all versions in publication order.
+    pub async fn execute(&self, network: Network, json_output: bool) -> Result<(), EduNewsError> {
+        let educhain_client = create_educhain_client(network).await?;
+
+        let anchor = match (&self.hash, self.collection_id, self.item_id) {
+            (Some(hash), _, _) => {
+                if !json_output {
+                    print_info(&format!("Showing history for content hash: {}", hash));
+                }
+                resolve_anchor(&educhain_client, parse_content_hash(hash)?).await?.ok_or_else(
+                    || EduNewsError::ArticleHashNotFound { hash: hash.clone() }
+                )?
+            }
+            (None, Some(collection_id), Some(item_id)) => {
+                if !json_output {
+                    print_info(
+                        &format!("Showing history for article: collection {}, item {}", collection_id, item_id)
+                    );
+                }
+                get_anchor_by_ids(&educhain_client, collection_id, item_id).await?.ok_or(
+                    EduNewsError::ArticleNotFound { collection_id, item_id }
+                )?
+            }
+            _ => unreachable!(), // clap handles this validation
+        };
+
+        let history = get_article_history(&educhain_client, anchor).await?.ok_or_else(|| {
+            EduNewsError::ArticleHashNotFound {
+                hash: format!("0x{}", hex::encode(anchor.0)),
+            }
+        })?;
+
+        let output = format_output(&history, json_output)?;
+        println!("{}", output);
+
+        Ok(())
+    }
+}
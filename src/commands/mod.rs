@@ -3,6 +3,12 @@ pub mod verify;
 pub mod list;
 pub mod identity;
 pub mod show;
+pub mod update;
+pub mod history;
+pub mod transfer;
+pub mod retract;
+pub mod collection;
+pub mod publications;
 
 use clap::{Args, Subcommand};
 use std::path::PathBuf;
@@ -19,6 +25,18 @@ pub enum Commands {
     Identity(IdentityArgs),
     /// Show article details
     Show(ShowArgs),
+    /// Publish a new version of an existing article
+    Update(UpdateArgs),
+    /// Show the version history of an article
+    History(HistoryArgs),
+    /// Transfer an article's NFT to another account
+    Transfer(TransferArgs),
+    /// Retract (withdraw) a published article
+    Retract(RetractArgs),
+    /// Show or update a publication's collection
+    Collection(CollectionArgs),
+    /// List the publications (collections) of an account
+    Publications(PublicationsArgs),
 }
 
 #[derive(Args)]
@@ -42,17 +60,69 @@ pub struct RegisterArgs {
     /// Mnemonic phrase for signing (or use EDUNEWS_MNEMONIC env var)
     #[arg(long, env = "EDUNEWS_MNEMONIC")]
     pub mnemonic: String,
+
+    /// Mint into this AssetHub collection instead of looking up the publisher's own
+    #[arg(long, conflicts_with = "publication")]
+    pub collection_id: Option<u32>,
+
+    /// Mint into the publisher's collection with this publication name, creating it on first use
+    #[arg(long, conflicts_with = "publication_name")]
+    pub publication: Option<String>,
+
+    /// Continue an interrupted registration from its journal
+    #[arg(long)]
+    pub resume: bool,
+
+    /// Burn the minted NFT and clear its metadata if EduChain registration fails
+    #[arg(long)]
+    pub rollback_on_failure: bool,
+
+    /// Leave the NFT metadata editable instead of locking it after registration
+    #[arg(long)]
+    pub no_lock_metadata: bool,
+
+    /// Also lock the NFT attributes (a locked NFT can no longer be retracted)
+    #[arg(long)]
+    pub lock_attributes: bool,
+
+    /// Make the NFT non-transferable after registration
+    #[arg(long)]
+    pub lock_transfer: bool,
+
+    /// Directory holding registration journals
+    #[arg(long, env = "EDUNEWS_JOURNAL_DIR", default_value = ".edunews/journal")]
+    pub journal_dir: PathBuf,
+
+    /// Print the transactions, fees and deposits of the registration without submitting anything
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Publication profile used if a new collection has to be created
+    #[command(flatten)]
+    pub profile: ProfileArgs,
 }
 
 #[derive(Args)]
 pub struct VerifyArgs {
     /// Collection ID
-    #[arg(long)]
-    pub collection_id: u32,
+    #[arg(long, requires = "item_id", required_unless_present_any = ["hash", "content", "content_file"])]
+    pub collection_id: Option<u32>,
     
     /// Item ID
-    #[arg(long)]
-    pub item_id: u32,
+    #[arg(long, requires = "collection_id")]
+    pub item_id: Option<u32>,
+
+    /// Content hash of any version of the article (0x...)
+    #[arg(long, conflicts_with_all = ["collection_id", "item_id"])]
+    pub hash: Option<String>,
+
+    /// Local article content to check against the on-chain hash (inline)
+    #[arg(long, conflicts_with = "content_file")]
+    pub content: Option<String>,
+
+    /// Path to a local copy of the article to check against the on-chain hash
+    #[arg(long, conflicts_with = "content")]
+    pub content_file: Option<PathBuf>,
 }
 
 #[derive(Args)]
@@ -71,11 +141,169 @@ pub struct IdentityArgs {
 
 #[derive(Args)]
 pub struct ShowArgs {
+    /// Collection ID
+    #[arg(long, requires = "item_id", required_unless_present = "hash")]
+    pub collection_id: Option<u32>,
+    
+    /// Item ID
+    #[arg(long, requires = "collection_id")]
+    pub item_id: Option<u32>,
+
+    /// Content hash of any version of the article (0x...)
+    #[arg(long, conflicts_with_all = ["collection_id", "item_id"])]
+    pub hash: Option<String>,
+
+    /// Show the provenance attributes stored on the AssetHub NFT instead
+    #[arg(long)]
+    pub nft: bool,
+}
+
+#[derive(Args)]
+pub struct UpdateArgs {
     /// Collection ID
     #[arg(long)]
     pub collection_id: u32,
-    
+
+    /// Item ID
+    #[arg(long)]
+    pub item_id: u32,
+
+    /// New article content (inline)
+    #[arg(long, conflicts_with = "content_file")]
+    pub content: Option<String>,
+
+    /// Path to file containing the new article content
+    #[arg(long, conflicts_with = "content")]
+    pub content_file: Option<PathBuf>,
+
+    /// New article title (defaults to the current title)
+    #[arg(long)]
+    pub title: Option<String>,
+
+    /// New article URL (defaults to the current URL)
+    #[arg(long)]
+    pub url: Option<String>,
+
+    /// Mnemonic phrase for signing (or use EDUNEWS_MNEMONIC env var)
+    #[arg(long, env = "EDUNEWS_MNEMONIC")]
+    pub mnemonic: String,
+}
+
+#[derive(Args)]
+pub struct HistoryArgs {
+    /// Collection ID
+    #[arg(long, requires = "item_id", required_unless_present = "hash")]
+    pub collection_id: Option<u32>,
+
+    /// Item ID
+    #[arg(long, requires = "collection_id")]
+    pub item_id: Option<u32>,
+
+    /// Content hash of any version of the article (0x...)
+    #[arg(long, conflicts_with_all = ["collection_id", "item_id"])]
+    pub hash: Option<String>,
+}
+
+#[derive(Args)]
+pub struct TransferArgs {
+    /// Collection ID
+    #[arg(long)]
+    pub collection_id: u32,
+
+    /// Item ID
+    #[arg(long)]
+    pub item_id: u32,
+
+    /// Address of the new owner
+    #[arg(long)]
+    pub to: String,
+
+    /// Mnemonic phrase for signing (or use EDUNEWS_MNEMONIC env var)
+    #[arg(long, env = "EDUNEWS_MNEMONIC")]
+    pub mnemonic: String,
+}
+
+#[derive(Args)]
+pub struct RetractArgs {
+    /// Collection ID
+    #[arg(long)]
+    pub collection_id: u32,
+
     /// Item ID
     #[arg(long)]
     pub item_id: u32,
-}
\ No newline at end of file
+
+    /// Why the article is retracted (shown by verify and show)
+    #[arg(long)]
+    pub reason: String,
+
+    /// Retraction notice to record as the article's final version on EduChain (inline)
+    #[arg(long, conflicts_with = "notice_file")]
+    pub notice: Option<String>,
+
+    /// Path to a file containing the retraction notice
+    #[arg(long, conflicts_with = "notice")]
+    pub notice_file: Option<PathBuf>,
+
+    /// Also burn the NFT after marking it retracted
+    #[arg(long)]
+    pub burn: bool,
+
+    /// Mnemonic phrase for signing (or use EDUNEWS_MNEMONIC env var)
+    #[arg(long, env = "EDUNEWS_MNEMONIC")]
+    pub mnemonic: String,
+}
+
+/// Publication profile of a collection, from a file and/or flags
+#[derive(Args, Default)]
+pub struct ProfileArgs {
+    /// JSON file with the publication profile (flags override its fields)
+    #[arg(long)]
+    pub profile_file: Option<PathBuf>,
+
+    /// Publication name
+    #[arg(long)]
+    pub publication_name: Option<String>,
+
+    /// Publication website
+    #[arg(long)]
+    pub website: Option<String>,
+
+    /// Hash of the publication logo (0x...)
+    #[arg(long)]
+    pub logo_hash: Option<String>,
+
+    /// Short description of the publication
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// Maximum number of articles in the collection
+    #[arg(long)]
+    pub max_supply: Option<u32>,
+
+    /// Whether newly minted article NFTs are transferable
+    #[arg(long)]
+    pub transferable: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct CollectionArgs {
+    /// Collection ID
+    #[arg(long)]
+    pub collection_id: u32,
+
+    /// Profile changes; the collection is only shown when none are given
+    #[command(flatten)]
+    pub profile: ProfileArgs,
+
+    /// Mnemonic phrase for signing updates (or use EDUNEWS_MNEMONIC env var)
+    #[arg(long, env = "EDUNEWS_MNEMONIC")]
+    pub mnemonic: Option<String>,
+}
+
+#[derive(Args)]
+pub struct PublicationsArgs {
+    /// Publisher address
+    #[arg(long)]
+    pub publisher: String,
+}
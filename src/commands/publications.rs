@@ -0,0 +1,44 @@
+use std::str::FromStr;
+use subxt::utils::AccountId32;
+
+use crate::chains::{ create_assethub_client, get_publications };
+use crate::commands::PublicationsArgs;
+use crate::error::EduNewsError;
+use crate::types::Network;
+use crate::utils::print_info;
+
+impl PublicationsArgs {
+    /// List every collection the publisher owns, with its publication profile
+    pub async fn execute(&self, network: Network, json_output: bool) -> Result<(), EduNewsError> {
+        let owner = AccountId32::from_str(&self.publisher)
+            .map_err(|_| EduNewsError::InvalidAddress { address: self.publisher.clone() })?;
+
+        if !json_output {
+            print_info(&format!("Listing publications for publisher: {}", self.publisher));
+        }
+
+        let assethub_client = create_assethub_client(network).await?;
+
+        let publications = get_publications(&assethub_client, &owner).await?;
+
+        if publications.is_empty() {
+            if json_output {
+                println!("[]");
+            } else {
+                print_info(&format!("No publications found for publisher: {}", self.publisher));
+            }
+        } else if json_output {
+            println!("{}", serde_json::to_string_pretty(&publications)?);
+        } else {
+            print_info(&format!("Found {} publications:\n", publications.len()));
+            for (i, publication) in publications.iter().enumerate() {
+                println!("{}. {}", i + 1, publication);
+                if i < publications.len() - 1 {
+                    println!();
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
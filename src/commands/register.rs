@@ -1,80 +1,366 @@
 use indicatif::{ ProgressBar, ProgressStyle };
-use std::fs;
+use subxt::utils::AccountId32;
 
-use crate::chains::{ create_assethub_client, create_educhain_client, create_nft, register_article };
+use crate::chains::{
+    check_article_exists,
+    create_assethub_client,
+    create_educhain_client,
+    create_nft,
+    CollectionTarget,
+    encode_collection_metadata,
+    encode_nft_metadata,
+    estimate_batch_fee,
+    estimate_lock_fees,
+    estimate_record_fee,
+    find_record_receipt,
+    get_article_by_hash,
+    get_assethub_existential_deposit,
+    get_assethub_free_balance,
+    get_educhain_existential_deposit,
+    get_educhain_free_balance,
+    lock_item_properties,
+    lock_item_transfer,
+    parse_content_hash,
+    plan_nft_calls,
+    register_article,
+    resolve_target_collection,
+    rollback_nft,
+    validate_registration,
+};
 use crate::commands::RegisterArgs;
 use crate::error::EduNewsError;
-use crate::types::{ Network, RegistrationResult };
-use crate::utils::{ create_keypair_from_mnemonic, format_output, hash_content, print_success, print_info };
+use crate::journal::RegistrationJournal;
+use crate::types::{
+    ArticleNftMetadata,
+    BalanceCheck,
+    BalanceReport,
+    ChainPlan,
+    Network,
+    NftAttributes,
+    PlannedCost,
+    PublicationProfile,
+    RegistrationPlan,
+    RegistrationResult,
+    ARTICLE_NFT_SCHEMA_VERSION,
+};
+use crate::utils::{
+    create_keypair_from_mnemonic,
+    format_output,
+    hash_content,
+    load_content,
+    print_success,
+    print_info,
+    print_warning,
+    sign_content_hash,
+};
 
 impl RegisterArgs {
     /// Register article across AssetHub and EduChain
     ///
     /// Steps:
+    /// 0. Validate the registration against EduChain state, price every
+    ///    transaction and check both balances (nothing is sent yet)
     /// 1. Create NFT on AssetHub (gets collection_id and item_id)
     /// 2. Register article on EduChain (using the same IDs for linking)
+    /// 3. Lock the NFT metadata (unless `--no-lock-metadata`), and optionally
+    ///    its attributes and transfer
+    ///
+    /// Every finalized transaction is recorded in a local journal keyed by
+    /// content hash. With `--resume`, steps already in the journal are skipped.
+    /// With `--rollback-on-failure`, a failed EduChain step burns the NFT.
+    /// With `--dry-run`, the remaining steps are only priced and printed.
     pub async fn execute(&self, network: Network, json_output: bool) -> Result<(), EduNewsError> {
         // Load and validate content
-        let content = self.load_content()?;
+        let content = load_content(&self.content, &self.content_file)?;
         let word_count = content.split_whitespace().count() as u32;
 
         // Generate keypair from mnemonic
         let keypair = create_keypair_from_mnemonic(&self.mnemonic)?;
 
+        let content_hash_raw = hash_content(&content);
+        let content_hash = format!("0x{}", content_hash_raw);
+
+        // Pick up where an interrupted run stopped, or start a fresh journal
+        let mut journal = RegistrationJournal::open(
+            &self.journal_dir,
+            &content_hash,
+            &self.title,
+            &self.url,
+            self.resume
+        )?;
+        if self.resume && !json_output {
+            print_info(&format!("Resuming registration from {}", journal.path().display()));
+        }
+
         // Create progress bar for multi-step process
-        let pb = ProgressBar::new(3);
+        let pb = ProgressBar::new(5);
         pb.set_style(
             ProgressStyle::default_bar()
                 .template("{spinner:.green} [{elapsed_precise}] {msg}")
                 .unwrap()
         );
 
+        // Step 0: Fail fast before anything is minted on AssetHub
+        pb.set_message("Validating registration on EduChain...");
+        let educhain_client = create_educhain_client(network).await?;
+        let assethub_client = create_assethub_client(network).await?;
+
+        // A run can stop after record_article finalized but before the journal
+        // was saved: pick that record up instead of failing validation
+        if self.resume && !journal.is_complete() {
+            if let (Some(collection_id), Some(item_id)) = (journal.collection_id, journal.item_id) {
+                let anchor = parse_content_hash(&content_hash)?;
+                let recorded = get_article_by_hash(&educhain_client, anchor).await?
+                    .filter(|article| article.collection_id == collection_id && article.item_id == item_id);
+                if let Some(article) = recorded {
+                    let receipt = find_record_receipt(&educhain_client, network, anchor, article.timestamp as u32).await?
+                        .ok_or_else(|| EduNewsError::MissingEvent {
+                            chain: "EduChain".to_string(),
+                            event: "News.ArticleRecorded".to_string(),
+                        })?;
+                    journal.record_receipt = Some(receipt);
+                    journal.publisher = Some(article.publisher);
+                    journal.save()?;
+                }
+            }
+        }
+
+        if !journal.is_complete() {
+            validate_registration(
+                &educhain_client,
+                &keypair.public_key().into(),
+                &content_hash,
+                &self.title,
+                &self.url
+            ).await?;
+        }
+
+        // The NFT metadata links back to this article; the attributes add
+        // which EduChain holds it and where it's published
+        let nft_metadata = ArticleNftMetadata {
+            schema_version: ARTICLE_NFT_SCHEMA_VERSION,
+            title: self.title.clone(),
+            content_hash: content_hash.clone(),
+            canonical_url: None,
+            educhain_genesis: None,
+            anchor: None, // A new article is its own anchor
+        };
+        encode_nft_metadata(&assethub_client, &nft_metadata)?;
+        let nft_attributes = NftAttributes {
+            content_hash: Some(content_hash.clone()),
+            hash_algo: Some("blake2b256".to_string()),
+            educhain_genesis: Some(format!("0x{}", hex::encode(educhain_client.genesis_hash().0))),
+            canonical_url: Some(self.url.clone()),
+            word_count: Some(word_count),
+            ..Default::default()
+        };
+
+        // Profile for the publisher's collection, in case it doesn't exist yet
+        let mut profile = self.profile.resolve(PublicationProfile {
+            name: "news".to_string(),
+            ..Default::default()
+        })?;
+        let target = match (self.collection_id, &self.publication) {
+            (Some(collection_id), _) => CollectionTarget::Pinned(collection_id),
+            (None, Some(publication)) => {
+                profile.name = publication.clone();
+                CollectionTarget::Publication(publication.clone())
+            }
+            (None, None) => CollectionTarget::Default,
+        };
+        encode_collection_metadata(&assethub_client, &profile)?;
+
+        // Price every remaining transaction before sending any
+        pb.set_message("Estimating fees and deposits...");
+        let signer: AccountId32 = keypair.public_key().into();
+        let plan = {
+            // Plan against a copy, so the journal on disk stays untouched
+            let mut planned = journal.clone();
+            resolve_target_collection(&assethub_client, &keypair, &target, &mut planned).await?;
+            let nft_plan = plan_nft_calls(
+                &assethub_client,
+                &educhain_client,
+                &keypair,
+                &nft_metadata,
+                &nft_attributes,
+                &profile,
+                &planned,
+                0
+            ).await?;
+
+            let mut assethub_fees = Vec::new();
+            if !nft_plan.calls.is_empty() {
+                assethub_fees.push(PlannedCost {
+                    description: format!("utility.batch_all ({} nfts calls)", nft_plan.calls.len()),
+                    amount: estimate_batch_fee(&assethub_client, &signer, nft_plan.calls).await?,
+                });
+            }
+            let lock_metadata = !self.no_lock_metadata;
+            let lock_properties = (planned.properties_lock_receipt.is_none() && (lock_metadata || self.lock_attributes))
+                .then_some((lock_metadata, self.lock_attributes));
+            let lock_fees = estimate_lock_fees(
+                &assethub_client,
+                &signer,
+                nft_plan.collection_id,
+                nft_plan.item_id,
+                lock_properties,
+                self.lock_transfer && planned.transfer_lock_receipt.is_none()
+            ).await?;
+            assethub_fees.extend(lock_fees);
+
+            let mut educhain_fees = Vec::new();
+            if !planned.is_complete() {
+                educhain_fees.push(PlannedCost {
+                    description: "news.record_article".to_string(),
+                    amount: estimate_record_fee(
+                        &educhain_client,
+                        &signer,
+                        nft_plan.collection_id,
+                        nft_plan.item_id,
+                        &self.title,
+                        &self.url,
+                        &content_hash,
+                        word_count
+                    ).await?,
+                });
+            }
+
+            RegistrationPlan {
+                collection_id: nft_plan.collection_id,
+                item_id: nft_plan.item_id,
+                creates_collection: nft_plan.creates_collection,
+                assethub: ChainPlan::new("AssetHub", assethub_fees, nft_plan.deposits),
+                educhain: ChainPlan::new("EduChain", educhain_fees, Vec::new()),
+            }
+        };
+
+        // Dry run: stop before sending anything
+        if self.dry_run {
+            pb.finish_and_clear();
+            println!("{}", format_output(&plan, json_output)?);
+            return Ok(());
+        }
+
+        // Both chains must be able to pay before the first transaction, or
+        // the registration would stop halfway
+        let balances = BalanceReport {
+            checks: vec![
+                BalanceCheck::new(
+                    &plan.assethub,
+                    get_assethub_free_balance(&assethub_client, &signer).await?,
+                    get_assethub_existential_deposit(&assethub_client)?
+                ),
+                BalanceCheck::new(
+                    &plan.educhain,
+                    get_educhain_free_balance(&educhain_client, &signer).await?,
+                    get_educhain_existential_deposit(&educhain_client)?
+                )
+            ],
+        };
+        let short_chains = balances.short_chains();
+        if !short_chains.is_empty() {
+            pb.finish_and_clear();
+            println!("{}", format_output(&balances, json_output)?);
+            return Err(EduNewsError::InsufficientBalance { chains: short_chains.join(", ") });
+        }
+        pb.inc(1);
+
         // Step 1: Create NFT on AssetHub
         pb.set_message("Creating NFT on AssetHub...");
-        let assethub_client = create_assethub_client(network).await?;
-        let content_hash_raw = hash_content(&content);
-        let content_hash = format!("0x{}", content_hash_raw);
-        let (collection_id, item_id) = create_nft(
+        let (collection_id, item_id, assethub_mint) = create_nft(
             &assethub_client,
+            &educhain_client,
             &keypair,
-            &self.title,
-            &content_hash
+            &nft_metadata,
+            &nft_attributes,
+            &target,
+            &profile,
+            &mut journal
         ).await?;
         pb.inc(1);
 
-        // Convert hex string back to bytes for signing (without 0x prefix)
-        let content_hash_bytes = hex
-            ::decode(&content_hash_raw)
-            .map_err(|_| EduNewsError::InvalidContentHash {
-                hash: content_hash_raw.clone(),
-            })?;
+        // Step 2: Register article on EduChain
+        pb.set_message("Registering article on EduChain...");
+        let (educhain_record, publisher) = match (journal.record_receipt.clone(), journal.publisher.clone()) {
+            (Some(receipt), Some(publisher)) => (receipt, publisher),
+            _ => {
+                let signature = sign_content_hash(&keypair, &content_hash_raw)?;
 
-        let mut wrapped_msg = b"<Bytes>".to_vec();
-        wrapped_msg.extend_from_slice(&content_hash_bytes);
-        wrapped_msg.extend_from_slice(b"</Bytes>");
+                let recorded = register_article(
+                    &educhain_client,
+                    &keypair,
+                    collection_id,
+                    signature,
+                    item_id,
+                    &self.title,
+                    &self.url,
+                    &content_hash,
+                    word_count
+                ).await;
 
-        print_info(&format!("Debug: Signing message: {:?}", String::from_utf8_lossy(&wrapped_msg)));
-        print_info(&format!("Debug: Content hash: {}", content_hash));
+                let (receipt, publisher) = match recorded {
+                    Ok(recorded) => recorded,
+                    Err(e) if self.rollback_on_failure => {
+                        // A connection or subscription error can come after the record was
+                        // broadcast: only burn the NFT once EduChain shows no record for it
+                        let may_be_recorded = if e.is_definitive_failure() {
+                            false
+                        } else {
+                            let by_hash = get_article_by_hash(&educhain_client, parse_content_hash(&content_hash)?).await;
+                            let by_ids = check_article_exists(&educhain_client, collection_id, item_id).await;
+                            !matches!((by_hash, by_ids), (Ok(None), Ok(false)))
+                        };
+                        if may_be_recorded {
+                            pb.finish_and_clear();
+                            print_warning(
+                                "Not rolling back: EduChain may have recorded the article, rerun with --resume to finish the registration"
+                            );
+                            return Err(e);
+                        }
 
-        let signature = keypair.sign(&wrapped_msg);
+                        // Leave no orphan NFT behind on AssetHub
+                        pb.set_message("Rolling back NFT on AssetHub...");
+                        let report = rollback_nft(&assethub_client, &keypair, collection_id, item_id).await;
+                        pb.finish_and_clear();
+                        if report.errors.is_empty() {
+                            journal.discard()?;
+                        }
+                        println!("{}", format_output(&report, json_output)?);
+                        return Err(e);
+                    }
+                    Err(e) => return Err(e),
+                };
+                journal.record_receipt = Some(receipt.clone());
+                journal.publisher = Some(publisher.clone());
+                journal.save()?;
+                (receipt, publisher)
+            }
+        };
+        pb.inc(1);
 
-        // Step 2: Register article on EduChain
-        pb.set_message("Registering article on EduChain...");
-        let educhain_client = create_educhain_client(network).await?;
-        let tx_hash = register_article(
-            &educhain_client,
-            &keypair,
-            collection_id,
-            signature.into(),
-            item_id,
-            &self.title,
-            &self.url,
-            &content_hash,
-            word_count
-        ).await?;
+        // Step 3: Lock the NFT now that both chains agree on it
+        pb.set_message("Locking NFT on AssetHub...");
+        let lock_metadata = !self.no_lock_metadata;
+        if (lock_metadata || self.lock_attributes) && journal.properties_lock_receipt.is_none() {
+            let receipt = lock_item_properties(
+                &assethub_client,
+                &keypair,
+                collection_id,
+                item_id,
+                lock_metadata,
+                self.lock_attributes
+            ).await?;
+            journal.properties_lock_receipt = Some(receipt);
+            journal.save()?;
+        }
+        if self.lock_transfer && journal.transfer_lock_receipt.is_none() {
+            let receipt = lock_item_transfer(&assethub_client, &keypair, collection_id, item_id).await?;
+            journal.transfer_lock_receipt = Some(receipt);
+            journal.save()?;
+        }
         pb.inc(1);
 
-        // Step 3: Verify registration
+        // Step 4: Verify registration
         pb.set_message("Verifying registration...");
         pb.inc(1);
         pb.finish_and_clear();
@@ -83,8 +369,13 @@ impl RegisterArgs {
         let result = RegistrationResult {
             collection_id,
             item_id,
-            tx_hash,
+            tx_hash: educhain_record.extrinsic_hash.clone(),
             content_hash,
+            publisher,
+            assethub_mint,
+            educhain_record,
+            properties_locked: journal.properties_lock_receipt.clone(),
+            transfer_locked: journal.transfer_lock_receipt.clone(),
         };
 
         // Output result
@@ -98,17 +389,4 @@ impl RegisterArgs {
 
         Ok(())
     }
-
-    fn load_content(&self) -> Result<String, EduNewsError> {
-        match (&self.content, &self.content_file) {
-            (Some(content), None) => Ok(content.clone()),
-            (None, Some(path)) =>
-                fs::read_to_string(path).map_err(|e| EduNewsError::FileRead {
-                    path: path.clone(),
-                    source: e,
-                }),
-            (None, None) => Err(EduNewsError::NoContentProvided),
-            (Some(_), Some(_)) => unreachable!(), // clap handles this validation
-        }
-    }
 }
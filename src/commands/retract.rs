@@ -0,0 +1,104 @@
+use crate::chains::{
+    burn_item,
+    create_assethub_client,
+    create_educhain_client,
+    get_article_by_ids,
+    retract_item,
+};
+use crate::commands::RetractArgs;
+use crate::commands::update::publish_version;
+use crate::error::EduNewsError;
+use crate::types::{ Network, RetractResult };
+use crate::utils::{ create_keypair_from_mnemonic, format_output, load_content, print_info, print_success };
+
+impl RetractArgs {
+    /// Retract a published article
+    ///
+    /// Steps:
+    /// 1. Optionally record the retraction notice as a final version on EduChain
+    /// 2. Mark the NFT retracted with the reason (`retracted` attribute on AssetHub)
+    /// 3. Optionally burn the NFT
+    ///
+    /// Nothing is deleted from EduChain: the article and its history stay
+    /// verifiable, and `verify`/`show` report the retraction.
+    pub async fn execute(&self, network: Network, json_output: bool) -> Result<(), EduNewsError> {
+        // Load the notice first, so a bad path fails before anything is sent
+        let notice = match (&self.notice, &self.notice_file) {
+            (None, None) => None,
+            _ => Some(load_content(&self.notice, &self.notice_file)?),
+        };
+
+        // Generate keypair from mnemonic
+        let keypair = create_keypair_from_mnemonic(&self.mnemonic)?;
+
+        if !json_output {
+            print_info(
+                &format!("Retracting article: collection {}, item {}", self.collection_id, self.item_id)
+            );
+        }
+
+        let educhain_client = create_educhain_client(network).await?;
+        let assethub_client = create_assethub_client(network).await?;
+
+        // Only registered articles can be retracted
+        get_article_by_ids(&educhain_client, self.collection_id, self.item_id).await?
+            .ok_or(EduNewsError::ArticleNotFound {
+                collection_id: self.collection_id,
+                item_id: self.item_id,
+            })?;
+
+        // Step 1: Record the notice while the publisher can still sign for the article
+        let final_version = match &notice {
+            Some(notice) => {
+                Some(
+                    publish_version(
+                        &educhain_client,
+                        &keypair,
+                        self.collection_id,
+                        self.item_id,
+                        notice,
+                        None,
+                        None
+                    ).await?
+                )
+            }
+            None => None,
+        };
+
+        // Step 2: Mark the NFT, before any burn, so the reason stays readable
+        let retraction = retract_item(
+            &assethub_client,
+            &keypair,
+            self.collection_id,
+            self.item_id,
+            &self.reason
+        ).await?;
+
+        // Step 3: Burn the NFT if requested
+        let item_burned = if self.burn {
+            Some(burn_item(&assethub_client, &keypair, self.collection_id, self.item_id).await?)
+        } else {
+            None
+        };
+
+        let result = RetractResult {
+            collection_id: self.collection_id,
+            item_id: self.item_id,
+            reason: self.reason.clone(),
+            retraction,
+            final_version,
+            item_burned,
+        };
+
+        // Output result
+        let output = format_output(&result, json_output)?;
+        if json_output {
+            println!("{}", output);
+        } else {
+            print_success("Article retracted");
+            println!("{}", output);
+        }
+
+        Ok(())
+    }
+}
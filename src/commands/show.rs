@@ -1,4 +1,4 @@
-use crate::chains::{create_educhain_client, create_assethub_client, create_peoplehub_client, get_article_by_ids, check_nft_exists, is_identity_verified};
+use crate::chains::{create_educhain_client, create_assethub_client, create_peoplehub_client, get_article_by_ids, get_article_by_hash, get_version_info, parse_content_hash, get_nft_attributes, get_nft_metadata, get_nft_owner, get_retraction, is_identity_verified};
 use crate::commands::ShowArgs;
 use crate::error::EduNewsError;
 use crate::types::Network;
@@ -6,29 +6,52 @@ use crate::utils::{format_output, print_info};
 
 impl ShowArgs {
     pub async fn execute(&self, network: Network, json_output: bool) -> Result<(), EduNewsError> {
-        print_info(&format!("Showing details for article: collection {}, item {}", self.collection_id, self.item_id));
-        
+        if self.nft {
+            return self.show_nft(network, json_output).await;
+        }
+
         let educhain_client = create_educhain_client(network).await?;
         let assethub_client = create_assethub_client(network).await?;
         let peoplehub_client = create_peoplehub_client(network).await?;
         
-        // Get the specific article directly from storage
-        match get_article_by_ids(&educhain_client, self.collection_id, self.item_id).await? {
+        // Get the specific article directly from storage, either by NFT ids or by version hash
+        let article = match (&self.hash, self.collection_id, self.item_id) {
+            (Some(hash), _, _) => {
+                print_info(&format!("Showing details for content hash: {}", hash));
+                get_article_by_hash(&educhain_client, parse_content_hash(hash)?).await?
+            }
+            (None, Some(collection_id), Some(item_id)) => {
+                print_info(&format!("Showing details for article: collection {}, item {}", collection_id, item_id));
+                get_article_by_ids(&educhain_client, collection_id, item_id).await?
+            }
+            _ => unreachable!(), // clap handles this validation
+        };
+        
+        match article {
             Some(mut article) => {
                 // Update verification status
-                article.verified_nft = check_nft_exists(&assethub_client, self.collection_id, self.item_id).await?;
+                article.nft_owner = get_nft_owner(&assethub_client, article.collection_id, article.item_id).await?;
+                article.verified_nft = article.nft_owner.is_some();
+                article.nft_metadata = get_nft_metadata(&assethub_client, article.collection_id, article.item_id).await?;
+                article.retracted = get_retraction(&assethub_client, article.collection_id, article.item_id).await?;
                 article.verified_identity = is_identity_verified(&peoplehub_client, &article.publisher).await.unwrap_or(false);
                 
+                // Place this version within the article's lineage
+                let content_hash = parse_content_hash(&article.content_hash)?;
+                article.version = get_version_info(&educhain_client, content_hash).await?;
+                
                 let output = format_output(&article, json_output)?;
                 println!("{}", output);
             }
             None => {
                 if json_output {
                     println!("null");
+                } else if let Some(hash) = &self.hash {
+                    return Err(EduNewsError::ArticleHashNotFound { hash: hash.clone() });
                 } else {
                     return Err(EduNewsError::ArticleNotFound {
-                        collection_id: self.collection_id,
-                        item_id: self.item_id,
+                        collection_id: self.collection_id.unwrap_or_default(),
+                        item_id: self.item_id.unwrap_or_default(),
                     });
                 }
             }
@@ -36,4 +59,29 @@ impl ShowArgs {
         
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// Show the provenance attributes stored on the article's NFT
+    async fn show_nft(&self, network: Network, json_output: bool) -> Result<(), EduNewsError> {
+        let assethub_client = create_assethub_client(network).await?;
+
+        // NFT ids are given directly, or taken from the EduChain record of the hash
+        let (collection_id, item_id) = match (&self.hash, self.collection_id, self.item_id) {
+            (Some(hash), _, _) => {
+                let educhain_client = create_educhain_client(network).await?;
+                let article = get_article_by_hash(&educhain_client, parse_content_hash(hash)?).await?
+                    .ok_or_else(|| EduNewsError::ArticleHashNotFound { hash: hash.clone() })?;
+                (article.collection_id, article.item_id)
+            }
+            (None, Some(collection_id), Some(item_id)) => (collection_id, item_id),
+            _ => unreachable!(), // clap handles this validation
+        };
+
+        print_info(&format!("Showing NFT attributes: collection {}, item {}", collection_id, item_id));
+        let attributes = get_nft_attributes(&assethub_client, collection_id, item_id).await?;
+
+        let output = format_output(&attributes, json_output)?;
+        println!("{}", output);
+
+        Ok(())
+    }
+}
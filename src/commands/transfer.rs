@@ -0,0 +1,81 @@
+use std::str::FromStr;
+use subxt::utils::AccountId32;
+
+use crate::chains::{
+    create_assethub_client,
+    create_educhain_client,
+    get_article_by_ids,
+    get_nft_owner,
+    transfer_item,
+};
+use crate::commands::TransferArgs;
+use crate::error::EduNewsError;
+use crate::types::{ Network, TransferResult };
+use crate::utils::{ create_keypair_from_mnemonic, format_output, print_info, print_warning };
+
+impl TransferArgs {
+    /// Transfer an article's NFT on AssetHub
+    ///
+    /// Only the NFT moves: the EduChain record keeps naming the original
+    /// publisher, so `verify` will report that the owner no longer matches.
+    pub async fn execute(&self, network: Network, json_output: bool) -> Result<(), EduNewsError> {
+        let dest = AccountId32::from_str(&self.to)
+            .map_err(|_| EduNewsError::InvalidAddress { address: self.to.clone() })?;
+
+        // Generate keypair from mnemonic
+        let keypair = create_keypair_from_mnemonic(&self.mnemonic)?;
+
+        if !json_output {
+            print_info(
+                &format!(
+                    "Transferring NFT: collection {}, item {} to {}",
+                    self.collection_id,
+                    self.item_id,
+                    dest
+                )
+            );
+        }
+
+        let assethub_client = create_assethub_client(network).await?;
+        let educhain_client = create_educhain_client(network).await?;
+
+        let from = get_nft_owner(&assethub_client, self.collection_id, self.item_id).await?
+            .ok_or(EduNewsError::NftUnknownItem)?;
+
+        // The EduChain record is immutable with respect to ownership
+        let publisher = get_article_by_ids(&educhain_client, self.collection_id, self.item_id).await?
+            .map(|article| article.publisher);
+        if let Some(publisher) = &publisher {
+            if !json_output && *publisher != dest.to_string() {
+                print_warning(
+                    &format!(
+                        "EduChain still names {} as the publisher: after this transfer the NFT owner no longer matches it",
+                        publisher
+                    )
+                );
+            }
+        }
+
+        let receipt = transfer_item(
+            &assethub_client,
+            &keypair,
+            self.collection_id,
+            self.item_id,
+            dest.clone()
+        ).await?;
+
+        let result = TransferResult {
+            collection_id: self.collection_id,
+            item_id: self.item_id,
+            from,
+            to: dest.to_string(),
+            publisher,
+            receipt,
+        };
+
+        let output = format_output(&result, json_output)?;
+        println!("{}", output);
+
+        Ok(())
+    }
+}
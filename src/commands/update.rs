@@ -0,0 +1,123 @@
+use subxt_signer::sr25519::Keypair;
+
+use crate::chains::{
+    create_educhain_client,
+    get_article_by_hash,
+    get_article_by_ids,
+    get_latest_version_hash,
+    parse_content_hash,
+    update_article,
+    EduChainClient,
+};
+use crate::commands::UpdateArgs;
+use crate::error::EduNewsError;
+use crate::types::{ Network, UpdateResult };
+use crate::utils::{
+    create_keypair_from_mnemonic,
+    format_output,
+    hash_content,
+    load_content,
+    print_info,
+    print_success,
+    sign_content_hash,
+};
+
+impl UpdateArgs {
+    /// Publish a new version of an article on EduChain
+    ///
+    /// The NFT on AssetHub is untouched: it keeps pointing at the anchor,
+    /// and the anchor's history links every later version back to it.
+    pub async fn execute(&self, network: Network, json_output: bool) -> Result<(), EduNewsError> {
+        // Load and validate content
+        let content = load_content(&self.content, &self.content_file)?;
+
+        // Generate keypair from mnemonic
+        let keypair = create_keypair_from_mnemonic(&self.mnemonic)?;
+
+        if !json_output {
+            print_info(
+                &format!("Updating article: collection {}, item {}", self.collection_id, self.item_id)
+            );
+        }
+
+        let educhain_client = create_educhain_client(network).await?;
+
+        let result = publish_version(
+            &educhain_client,
+            &keypair,
+            self.collection_id,
+            self.item_id,
+            &content,
+            self.title.as_deref(),
+            self.url.as_deref()
+        ).await?;
+
+        // Output result
+        let output = format_output(&result, json_output)?;
+        if json_output {
+            println!("{}", output);
+        } else {
+            print_success("Article updated successfully!");
+            println!("{}", output);
+        }
+
+        Ok(())
+    }
+}
+
+/// Append new content as the latest version of an article
+///
+/// Steps:
+/// 1. Resolve (collection_id, item_id) to the article's anchor hash
+/// 2. Find the latest version in the anchor's history
+/// 3. Hash and sign the new content, then submit `update_article`
+///
+/// Title and URL default to those of the latest version.
+pub async fn publish_version(
+    educhain_client: &EduChainClient,
+    keypair: &Keypair,
+    collection_id: u32,
+    item_id: u32,
+    content: &str,
+    title: Option<&str>,
+    url: Option<&str>
+) -> Result<UpdateResult, EduNewsError> {
+    let word_count = content.split_whitespace().count() as u32;
+
+    // Step 1: Resolve the anchor through RootByItem
+    let anchor = get_article_by_ids(educhain_client, collection_id, item_id).await?
+        .ok_or(EduNewsError::ArticleNotFound { collection_id, item_id })?;
+
+    // Step 2: The version being superseded is the last entry in the history
+    let anchor_hash = parse_content_hash(&anchor.content_hash)?;
+    let latest_hash = get_latest_version_hash(educhain_client, anchor_hash).await?;
+    let latest = get_article_by_hash(educhain_client, latest_hash).await?.unwrap_or(anchor);
+
+    // Step 3: Hash and sign the new content the same way as registration
+    let content_hash_raw = hash_content(content);
+    let content_hash = format!("0x{}", content_hash_raw);
+    let signature = sign_content_hash(keypair, &content_hash_raw)?;
+
+    let title = title.unwrap_or(&latest.title);
+    let url = url.unwrap_or(&latest.url);
+
+    let (version, receipt) = update_article(
+        educhain_client,
+        keypair,
+        &latest.content_hash,
+        &content_hash,
+        signature,
+        title,
+        url,
+        word_count
+    ).await?;
+
+    Ok(UpdateResult {
+        collection_id,
+        item_id,
+        previous_hash: latest.content_hash,
+        content_hash,
+        version,
+        receipt,
+    })
+}
@@ -1,51 +1,160 @@
-use crate::chains::{create_assethub_client, create_educhain_client, create_peoplehub_client, check_article_exists, check_nft_exists, is_identity_verified, get_article_by_ids};
+use crate::chains::{create_assethub_client, create_educhain_client, create_peoplehub_client, check_article_exists, is_identity_verified, get_article_by_ids, get_article_by_hash, get_item_lock_state, get_nft_metadata, get_nft_owner, get_retraction, get_version_info, match_content, parse_content_hash, AssetHubClient, EduChainClient};
 use crate::commands::VerifyArgs;
 use crate::error::EduNewsError;
-use crate::types::{Network, VerificationResult};
-use crate::utils::{format_output, print_info};
+use crate::types::{Article, ContentMatch, Network, NftLink, VerificationResult};
+use crate::utils::{format_output, hash_content, load_content, print_info, verify_content_signature};
 
 impl VerifyArgs {
     /// Verify article across multiple chains
     /// 
     /// This demonstrates a simple multi-chain verification:
     /// 1. Check if article exists on EduChain
-    /// 2. Check if NFT exists on AssetHub  
+    /// 2. Check if NFT exists on AssetHub, that its metadata points back
+    ///    to the EduChain record, and that the publisher still owns it
     /// 3. Check if publisher has identity on PeopleHub
+    /// 4. Re-check the publisher's signature over the content hash offline
+    ///
+    /// When a content hash is given instead of NFT ids, the record is read
+    /// straight from ArticleByHash and the NFT ids come from that record.
+    ///
+    /// When local content is given, it is hashed like at registration and
+    /// compared against the article's version history (tamper detection).
+    /// Without ids or a hash, the local content's own hash is looked up.
     pub async fn execute(&self, network: Network, json_output: bool) -> Result<(), EduNewsError> {
-        if !json_output {
-            print_info(&format!("Verifying article: collection {}, item {}", self.collection_id, self.item_id));
-        }
-        
+        // Hash the local copy of the article, if one was provided
+        let local_hash = match (&self.content, &self.content_file) {
+            (None, None) => None,
+            _ => {
+                let content = load_content(&self.content, &self.content_file)?;
+                Some(format!("0x{}", hash_content(&content)))
+            }
+        };
+        let lookup_hash = self.hash.clone().or_else(|| {
+            if self.collection_id.is_none() { local_hash.clone() } else { None }
+        });
+
         // Create clients for all three parachains
         let educhain_client = create_educhain_client(network).await?;
         let assethub_client = create_assethub_client(network).await?;
         let peoplehub_client = create_peoplehub_client(network).await?;
         
         // Check article existence on EduChain
-        let article_exists = check_article_exists(&educhain_client, self.collection_id, self.item_id).await?;
+        let (collection_id, item_id, article) = match (&lookup_hash, self.collection_id, self.item_id) {
+            (Some(hash), _, _) => {
+                if !json_output {
+                    print_info(&format!("Verifying content hash: {}", hash));
+                }
+                match get_article_by_hash(&educhain_client, parse_content_hash(hash)?).await? {
+                    Some(article) => (article.collection_id, article.item_id, Some(article)),
+                    None => (0, 0, None),
+                }
+            }
+            (None, Some(collection_id), Some(item_id)) => {
+                if !json_output {
+                    print_info(&format!("Verifying article: collection {}, item {}", collection_id, item_id));
+                }
+                let article = if check_article_exists(&educhain_client, collection_id, item_id).await? {
+                    get_article_by_ids(&educhain_client, collection_id, item_id).await?
+                } else {
+                    None
+                };
+                (collection_id, item_id, article)
+            }
+            _ => unreachable!(), // clap handles this validation
+        };
+        let article_exists = article.is_some();
         
-        // Check NFT existence on AssetHub
-        let nft_exists = check_nft_exists(&assethub_client, self.collection_id, self.item_id).await?;
+        // Check NFT existence and ownership on AssetHub (an unknown hash has no NFT link to check)
+        let current_owner = if article_exists || lookup_hash.is_none() {
+            get_nft_owner(&assethub_client, collection_id, item_id).await?
+        } else {
+            None
+        };
+        let nft_exists = current_owner.is_some();
+
+        // A transferred or sold NFT no longer proves the publisher's ownership
+        let owner_matches_publisher = match (&article, &current_owner) {
+            (Some(article), Some(owner)) => Some(*owner == article.publisher),
+            _ => None,
+        };
         
         // Check publisher identity (only if article exists)
-        let publisher_verified = if article_exists {
-            match get_article_by_ids(&educhain_client, self.collection_id, self.item_id).await? {
-                Some(article) => {
-                    is_identity_verified(&peoplehub_client, &article.publisher).await.unwrap_or(false)
-                }
-                None => false,
+        let publisher_verified = match &article {
+            Some(article) => {
+                is_identity_verified(&peoplehub_client, &article.publisher).await.unwrap_or(false)
             }
+            None => false,
+        };
+
+        // Report whether the verified record is the latest version
+        let version = match &article {
+            Some(article) => {
+                get_version_info(&educhain_client, parse_content_hash(&article.content_hash)?).await?
+            }
+            None => None,
+        };
+
+        // Re-check the publisher's signature locally, independent of the chain's own check
+        let signature_valid = match &article {
+            Some(article) if article.signature_type == "sr25519" => {
+                Some(verify_content_signature(&article.publisher, &article.content_hash, &article.signature)?)
+            }
+            _ => None, // No record, or a scheme this CLI never signs with
+        };
+
+        // Locked metadata can't be rewritten by the collection owner anymore
+        let lock_state = if nft_exists {
+            get_item_lock_state(&assethub_client, collection_id, item_id).await?
         } else {
-            false
+            None
+        };
+
+        // A retraction outlives a burned NFT, so it is checked for any known article
+        let retracted = match &article {
+            Some(article) => get_retraction(&assethub_client, article.collection_id, article.item_id).await?,
+            None => None,
+        };
+
+        // Check that the NFT at these ids really is this article's NFT
+        let nft_link = match &article {
+            Some(article) if nft_exists => {
+                let anchor = version.as_ref().map_or(article.content_hash.as_str(), |version| version.anchor.as_str());
+                Some(check_nft_link(&educhain_client, &assethub_client, article, anchor).await?)
+            }
+            _ => None,
+        };
+
+        // Compare the local copy against every version in the article's lineage
+        let content_match = match (&local_hash, &version) {
+            (Some(local_hash), Some(version)) => {
+                Some(
+                    match_content(
+                        &educhain_client,
+                        parse_content_hash(&version.anchor)?,
+                        parse_content_hash(local_hash)?
+                    ).await?
+                )
+            }
+            (Some(_), None) => Some(ContentMatch::NoMatch),
+            (None, _) => None,
         };
         
         // Create and display results
         let result = VerificationResult {
-            collection_id: self.collection_id,
-            item_id: self.item_id,
+            collection_id,
+            item_id,
             article_exists,
             nft_exists,
             publisher_verified,
+            content_hash: article.as_ref().map(|article| article.content_hash.clone()).or(lookup_hash),
+            version,
+            content_match,
+            signature_valid,
+            nft_link,
+            current_owner,
+            owner_matches_publisher,
+            retracted,
+            lock_state,
         };
         
         let output = format_output(&result, json_output)?;
@@ -53,4 +162,46 @@ impl VerifyArgs {
         
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Cross-check the NFT's metadata against EduChain
+///
+/// The metadata's content hash must resolve through ArticleByHash to a
+/// record for the same NFT ids and the same lineage (anchor) as the
+/// verified article, and its title must match that record's title.
+async fn check_nft_link(
+    educhain_client: &EduChainClient,
+    assethub_client: &AssetHubClient,
+    article: &Article,
+    anchor: &str
+) -> Result<NftLink, EduNewsError> {
+    let metadata = match get_nft_metadata(assethub_client, article.collection_id, article.item_id).await? {
+        Some(metadata) => metadata,
+        None => return Ok(NftLink::MissingMetadata),
+    };
+
+    let record = match parse_content_hash(&metadata.content_hash) {
+        Ok(hash) => get_article_by_hash(educhain_client, hash).await?,
+        Err(_) => None,
+    };
+
+    let mut fields = Vec::new();
+    match record {
+        Some(record) => {
+            let same_nft = record.collection_id == article.collection_id && record.item_id == article.item_id;
+            if !same_nft || !metadata.anchor_hash().eq_ignore_ascii_case(anchor) {
+                fields.push("content_hash".to_string());
+            }
+            if record.title != metadata.title {
+                fields.push("title".to_string());
+            }
+        }
+        None => fields.push("content_hash".to_string()),
+    }
+
+    if fields.is_empty() {
+        Ok(NftLink::Linked)
+    } else {
+        Ok(NftLink::Mismatch { fields })
+    }
+}
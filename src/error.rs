@@ -1,21 +1,29 @@
 use std::path::PathBuf;
+use subxt::error::DispatchError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum EduNewsError {
     #[error("Failed to connect to {chain}: {source}")] ChainConnection {
         chain: String,
-        #[source] source: subxt::Error,
+        #[source] source: Box<subxt::Error>,
     },
 
     #[error("Invalid mnemonic phrase")]
     InvalidMnemonic,
 
+    #[error("A mnemonic is required for this change: pass --mnemonic or set EDUNEWS_MNEMONIC")]
+    MnemonicRequired,
+
     #[error("Article not found: collection {collection_id}, item {item_id}")] ArticleNotFound {
         collection_id: u32,
         item_id: u32,
     },
 
+    #[error("Article not found for content hash: {hash}")] ArticleHashNotFound {
+        hash: String,
+    },
+
     #[error("Failed to read file {path}: {source}")] FileRead {
         path: PathBuf,
         #[source] source: std::io::Error,
@@ -25,6 +33,10 @@ pub enum EduNewsError {
         address: String,
     },
 
+    #[error("Invalid account address: {address}")] InvalidAddress {
+        address: String,
+    },
+
     #[error("Collection not found: {collection_id}")] CollectionNotFound {
         collection_id: u32,
     },
@@ -35,7 +47,100 @@ pub enum EduNewsError {
     #[error("Invalid content hash: {hash}")]
     InvalidContentHash { hash: String },
 
-    #[error("Subxt error: {0}")] Subxt(#[from] subxt::Error),
+    #[error("Expected {event} event on {chain} was not emitted")] MissingEvent {
+        chain: String,
+        event: String,
+    },
+
+    #[error("AssetHub created collection {actual}, but the registration was planned for collection {expected}")]
+    CollectionMismatch {
+        expected: u32,
+        actual: u32,
+    },
+
+    #[error("Article {field} is {len} bytes, but the chain allows at most {max}")] FieldTooLong {
+        field: String,
+        len: usize,
+        max: u32,
+    },
+
+    #[error("A registration for {content_hash} is already in progress (journal: {path}): rerun with --resume to continue it")]
+    RegistrationPending {
+        content_hash: String,
+        path: PathBuf,
+    },
+
+    #[error("No registration journal found for {content_hash}: nothing to resume")]
+    JournalNotFound { content_hash: String },
+
+    #[error("Title or URL differ from the journaled registration of {content_hash}: resume with the original values")]
+    JournalMismatch { content_hash: String },
+
+    #[error("NFT metadata is {len} bytes, but AssetHub allows at most {max}: shorten the title")] NftMetadataTooLarge {
+        len: usize,
+        max: u32,
+    },
+
+    #[error("Insufficient balance on {chains} to pay for the registration: fund the account and retry")] InsufficientBalance {
+        chains: String,
+    },
+
+    #[error("Invalid signature: {signature}")]
+    InvalidSignature { signature: String },
+
+    // EduChain (pallet_news) dispatch errors
+    #[error("Article already registered on EduChain: this content hash exists, use `edunews update` to publish a new version")]
+    ArticleAlreadyExists,
+
+    #[error("EduChain has no article for this content hash: register it first, or check the hash")]
+    ArticleNotRegistered,
+
+    #[error("Publisher's article list on EduChain is full (MaxArticlesPerPublisher reached): publish from another account")]
+    PublisherArticleListFull,
+
+    #[error("Article history on EduChain is full (MaxHistoryLen reached): register the new content as a separate article")]
+    HistoryTooLong,
+
+    #[error("Only the original publisher can change this article: check that the mnemonic belongs to the publisher")]
+    NotArticlePublisher,
+
+    #[error("EduChain rejected the content signature ({reason}): the signature must be made by the submitting account")]
+    SignatureRejected { reason: String },
+
+    // AssetHub (pallet_nfts) dispatch errors
+    #[error("No permission on AssetHub: the signing account does not own or administer this collection or item")]
+    NftNoPermission,
+
+    #[error("NFT item ID already taken on AssetHub: retry so a fresh item ID is chosen")]
+    NftAlreadyExists,
+
+    #[error("Unknown NFT collection on AssetHub: check the collection ID")]
+    NftUnknownCollection,
+
+    #[error("Unknown NFT item on AssetHub: check the collection and item IDs")]
+    NftUnknownItem,
+
+    #[error("NFT is locked on AssetHub ({reason}): locked properties cannot be changed")]
+    NftLocked { reason: String },
+
+    #[error("NFT collection on AssetHub reached its max supply: use another collection")]
+    NftMaxSupplyReached,
+
+    #[error("NFT on AssetHub has no metadata to clear")]
+    NftMetadataNotFound,
+
+    #[error("NFT owner on AssetHub differs from what was expected: the item may have been transferred")]
+    NftWrongOwner,
+
+    #[error("Items in this AssetHub collection are non-transferable")]
+    NftItemsNonTransferable,
+
+    #[error("{pallet} pallet rejected the transaction: {error}")] PalletError {
+        pallet: String,
+        error: String,
+    },
+
+    #[error("Subxt error: {0}")] Subxt(Box<subxt::Error>),
 
     #[error("JSON serialization error: {0}")] Json(#[from] serde_json::Error),
 
@@ -45,3 +150,268 @@ pub enum EduNewsError {
 
     #[error("Hex decoding error: {0}")] Hex(#[from] hex::FromHexError),
 }
+
+impl From<subxt::Error> for EduNewsError {
+    /// Decode module errors from failed dispatches into typed variants
+    ///
+    /// Anything that isn't a pallet error (connection, codec, metadata)
+    /// stays wrapped as `EduNewsError::Subxt`.
+    fn from(err: subxt::Error) -> Self {
+        if let subxt::Error::Runtime(DispatchError::Module(module_error)) = &err {
+            if let Ok(details) = module_error.details() {
+                return EduNewsError::from_pallet_error(details.pallet.name(), &details.variant.name);
+            }
+        }
+        EduNewsError::Subxt(Box::new(err))
+    }
+}
+
+impl EduNewsError {
+    /// Process exit code, grouped by what the caller can do about the error
+    ///
+    /// - 2: invalid input or local state (fix the command and rerun)
+    /// - 3: the article, NFT, collection or publisher doesn't exist
+    /// - 4: a chain rejected the transaction or it can't be paid for
+    /// - 5: the chain couldn't be reached or answered unexpectedly
+    /// - 1: anything else (local I/O and encoding)
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            EduNewsError::InvalidMnemonic
+            | EduNewsError::MnemonicRequired
+            | EduNewsError::FileRead { .. }
+            | EduNewsError::InvalidAddress { .. }
+            | EduNewsError::NoContentProvided
+            | EduNewsError::InvalidContentHash { .. }
+            | EduNewsError::FieldTooLong { .. }
+            | EduNewsError::RegistrationPending { .. }
+            | EduNewsError::JournalNotFound { .. }
+            | EduNewsError::JournalMismatch { .. }
+            | EduNewsError::NftMetadataTooLarge { .. }
+            | EduNewsError::InvalidSignature { .. } => 2,
+
+            EduNewsError::ArticleNotFound { .. }
+            | EduNewsError::ArticleHashNotFound { .. }
+            | EduNewsError::PublisherNotFound { .. }
+            | EduNewsError::CollectionNotFound { .. }
+            | EduNewsError::ArticleNotRegistered
+            | EduNewsError::NftUnknownCollection
+            | EduNewsError::NftUnknownItem
+            | EduNewsError::NftMetadataNotFound => 3,
+
+            EduNewsError::InsufficientBalance { .. }
+            | EduNewsError::ArticleAlreadyExists
+            | EduNewsError::PublisherArticleListFull
+            | EduNewsError::HistoryTooLong
+            | EduNewsError::NotArticlePublisher
+            | EduNewsError::SignatureRejected { .. }
+            | EduNewsError::NftNoPermission
+            | EduNewsError::NftAlreadyExists
+            | EduNewsError::NftLocked { .. }
+            | EduNewsError::NftMaxSupplyReached
+            | EduNewsError::NftWrongOwner
+            | EduNewsError::NftItemsNonTransferable
+            | EduNewsError::PalletError { .. } => 4,
+
+            EduNewsError::ChainConnection { .. }
+            | EduNewsError::MissingEvent { .. }
+            | EduNewsError::CollectionMismatch { .. }
+            | EduNewsError::Subxt(_) => 5,
+
+            EduNewsError::Json(_)
+            | EduNewsError::Io(_)
+            | EduNewsError::Blake2(_)
+            | EduNewsError::Hex(_) => 1,
+        }
+    }
+
+    /// Whether a failed transaction certainly had no effect on chain
+    ///
+    /// Pallet errors, other dispatch errors and transactions rejected as
+    /// invalid were never applied, and input errors stop before submitting.
+    /// Connection and subscription errors can happen after the transaction
+    /// was broadcast, so its outcome is unknown.
+    pub fn is_definitive_failure(&self) -> bool {
+        match self {
+            EduNewsError::Subxt(err) =>
+                matches!(
+                    **err,
+                    subxt::Error::Runtime(_) | subxt::Error::Transaction(subxt::error::TransactionError::Invalid(_))
+                ),
+            EduNewsError::ChainConnection { .. }
+            | EduNewsError::MissingEvent { .. }
+            | EduNewsError::CollectionMismatch { .. }
+            | EduNewsError::Json(_)
+            | EduNewsError::Io(_) => false,
+            _ => true,
+        }
+    }
+
+    /// The variant name (e.g. `NftLocked`), a stable identifier for scripts
+    pub fn kind(&self) -> &'static str {
+        match self {
+            EduNewsError::ChainConnection { .. } => "ChainConnection",
+            EduNewsError::InvalidMnemonic => "InvalidMnemonic",
+            EduNewsError::MnemonicRequired => "MnemonicRequired",
+            EduNewsError::ArticleNotFound { .. } => "ArticleNotFound",
+            EduNewsError::ArticleHashNotFound { .. } => "ArticleHashNotFound",
+            EduNewsError::FileRead { .. } => "FileRead",
+            EduNewsError::PublisherNotFound { .. } => "PublisherNotFound",
+            EduNewsError::InvalidAddress { .. } => "InvalidAddress",
+            EduNewsError::CollectionNotFound { .. } => "CollectionNotFound",
+            EduNewsError::NoContentProvided => "NoContentProvided",
+            EduNewsError::InvalidContentHash { .. } => "InvalidContentHash",
+            EduNewsError::MissingEvent { .. } => "MissingEvent",
+            EduNewsError::CollectionMismatch { .. } => "CollectionMismatch",
+            EduNewsError::FieldTooLong { .. } => "FieldTooLong",
+            EduNewsError::RegistrationPending { .. } => "RegistrationPending",
+            EduNewsError::JournalNotFound { .. } => "JournalNotFound",
+            EduNewsError::JournalMismatch { .. } => "JournalMismatch",
+            EduNewsError::NftMetadataTooLarge { .. } => "NftMetadataTooLarge",
+            EduNewsError::InsufficientBalance { .. } => "InsufficientBalance",
+            EduNewsError::InvalidSignature { .. } => "InvalidSignature",
+            EduNewsError::ArticleAlreadyExists => "ArticleAlreadyExists",
+            EduNewsError::ArticleNotRegistered => "ArticleNotRegistered",
+            EduNewsError::PublisherArticleListFull => "PublisherArticleListFull",
+            EduNewsError::HistoryTooLong => "HistoryTooLong",
+            EduNewsError::NotArticlePublisher => "NotArticlePublisher",
+            EduNewsError::SignatureRejected { .. } => "SignatureRejected",
+            EduNewsError::NftNoPermission => "NftNoPermission",
+            EduNewsError::NftAlreadyExists => "NftAlreadyExists",
+            EduNewsError::NftUnknownCollection => "NftUnknownCollection",
+            EduNewsError::NftUnknownItem => "NftUnknownItem",
+            EduNewsError::NftLocked { .. } => "NftLocked",
+            EduNewsError::NftMaxSupplyReached => "NftMaxSupplyReached",
+            EduNewsError::NftMetadataNotFound => "NftMetadataNotFound",
+            EduNewsError::NftWrongOwner => "NftWrongOwner",
+            EduNewsError::NftItemsNonTransferable => "NftItemsNonTransferable",
+            EduNewsError::PalletError { .. } => "PalletError",
+            EduNewsError::Subxt(_) => "Subxt",
+            EduNewsError::Json(_) => "Json",
+            EduNewsError::Io(_) => "Io",
+            EduNewsError::Blake2(_) => "Blake2",
+            EduNewsError::Hex(_) => "Hex",
+        }
+    }
+
+    /// Map a pallet error name (as found in the chain metadata) to a variant
+    pub fn from_pallet_error(pallet: &str, error: &str) -> Self {
+        match (pallet, error) {
+            ("News", "ArticleAlreadyExists") => EduNewsError::ArticleAlreadyExists,
+            ("News", "ArticleNotFound") => EduNewsError::ArticleNotRegistered,
+            ("News", "PublisherArticleListFull") => EduNewsError::PublisherArticleListFull,
+            ("News", "HistoryTooLong") => EduNewsError::HistoryTooLong,
+            ("News", "NotArticlePublisher") => EduNewsError::NotArticlePublisher,
+            ("News", "SignatureInvalid" | "AccountIdNot32Bytes") =>
+                EduNewsError::SignatureRejected { reason: error.to_string() },
+
+            ("Nfts", "NoPermission") => EduNewsError::NftNoPermission,
+            ("Nfts", "AlreadyExists") => EduNewsError::NftAlreadyExists,
+            ("Nfts", "UnknownCollection") => EduNewsError::NftUnknownCollection,
+            ("Nfts", "UnknownItem") => EduNewsError::NftUnknownItem,
+            (
+                "Nfts",
+                "ItemLocked" | "LockedItemMetadata" | "LockedItemAttributes" | "LockedCollectionMetadata" | "LockedCollectionAttributes",
+            ) => EduNewsError::NftLocked { reason: error.to_string() },
+            ("Nfts", "MaxSupplyReached") => EduNewsError::NftMaxSupplyReached,
+            ("Nfts", "MetadataNotFound") => EduNewsError::NftMetadataNotFound,
+            ("Nfts", "WrongOwner") => EduNewsError::NftWrongOwner,
+            ("Nfts", "ItemsNonTransferable") => EduNewsError::NftItemsNonTransferable,
+
+            _ => EduNewsError::PalletError {
+                pallet: pallet.to_string(),
+                error: error.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_news_pallet_errors() {
+        assert!(matches!(
+            EduNewsError::from_pallet_error("News", "ArticleAlreadyExists"),
+            EduNewsError::ArticleAlreadyExists
+        ));
+        assert!(matches!(
+            EduNewsError::from_pallet_error("News", "ArticleNotFound"),
+            EduNewsError::ArticleNotRegistered
+        ));
+        assert!(matches!(
+            EduNewsError::from_pallet_error("News", "NotArticlePublisher"),
+            EduNewsError::NotArticlePublisher
+        ));
+        assert!(matches!(
+            EduNewsError::from_pallet_error("News", "SignatureInvalid"),
+            EduNewsError::SignatureRejected { reason } if reason == "SignatureInvalid"
+        ));
+    }
+
+    #[test]
+    fn maps_nfts_pallet_errors() {
+        assert!(matches!(EduNewsError::from_pallet_error("Nfts", "NoPermission"), EduNewsError::NftNoPermission));
+        assert!(matches!(EduNewsError::from_pallet_error("Nfts", "AlreadyExists"), EduNewsError::NftAlreadyExists));
+        assert!(matches!(
+            EduNewsError::from_pallet_error("Nfts", "MetadataNotFound"),
+            EduNewsError::NftMetadataNotFound
+        ));
+        assert!(matches!(
+            EduNewsError::from_pallet_error("Nfts", "LockedItemAttributes"),
+            EduNewsError::NftLocked { reason } if reason == "LockedItemAttributes"
+        ));
+    }
+
+    #[test]
+    fn keeps_unknown_errors_with_their_names() {
+        // Same error name, but from a pallet we don't decode
+        assert!(matches!(
+            EduNewsError::from_pallet_error("Uniques", "NoPermission"),
+            EduNewsError::PalletError { pallet, error } if pallet == "Uniques" && error == "NoPermission"
+        ));
+        assert!(matches!(
+            EduNewsError::from_pallet_error("Nfts", "BadWitness"),
+            EduNewsError::PalletError { pallet, error } if pallet == "Nfts" && error == "BadWitness"
+        ));
+    }
+
+    #[test]
+    fn tells_definitive_failures_from_unknown_outcomes() {
+        assert!(EduNewsError::ArticleAlreadyExists.is_definitive_failure());
+        assert!(EduNewsError::FieldTooLong { field: "title".to_string(), len: 300, max: 256 }.is_definitive_failure());
+        assert!(
+            EduNewsError::Subxt(
+                Box::new(subxt::Error::Transaction(subxt::error::TransactionError::Invalid("stale".to_string())))
+            ).is_definitive_failure()
+        );
+
+        assert!(!EduNewsError::Subxt(Box::new(subxt::Error::Other("subscription closed".to_string()))).is_definitive_failure());
+        assert!(
+            !EduNewsError::Subxt(
+                Box::new(subxt::Error::Transaction(subxt::error::TransactionError::Dropped("pool".to_string())))
+            ).is_definitive_failure()
+        );
+        assert!(
+            !(EduNewsError::MissingEvent {
+                chain: "EduChain".to_string(),
+                event: "News.ArticleRecorded".to_string(),
+            }).is_definitive_failure()
+        );
+    }
+
+    #[test]
+    fn names_and_groups_errors_for_scripts() {
+        let locked = EduNewsError::NftLocked { reason: "ItemLocked".to_string() };
+        assert_eq!(locked.kind(), "NftLocked");
+        assert_eq!(locked.exit_code(), 4);
+
+        assert_eq!(EduNewsError::InvalidMnemonic.kind(), "InvalidMnemonic");
+        assert_eq!(EduNewsError::InvalidMnemonic.exit_code(), 2);
+        assert_eq!(EduNewsError::NftUnknownItem.exit_code(), 3);
+
+        let io = EduNewsError::Io(std::io::Error::other("disk full"));
+        assert_eq!(io.kind(), "Io");
+        assert_eq!(io.exit_code(), 1);
+    }
+}
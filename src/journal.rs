@@ -0,0 +1,220 @@
+use serde::{ Deserialize, Serialize };
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use crate::error::EduNewsError;
+use crate::types::TxReceipt;
+
+/// Local record of a multi-chain registration in progress
+///
+/// Registration takes several finalized transactions across two chains: one
+/// `utility.batch_all` that creates the collection if needed, mints the item
+/// and sets its metadata and attributes (AssetHub), then `record_article`
+/// (EduChain) and finally the optional item locks (AssetHub). The journal
+/// is saved after each one, so `register --resume` can continue from the
+/// last completed step instead of minting a second NFT. Each AssetHub call
+/// has its own flag, so journals written step by step still resume.
+///
+/// Journals are stored as `<journal_dir>/<content_hash>.json`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RegistrationJournal {
+    pub content_hash: String,
+    pub title: String,
+    pub url: String,
+    pub collection_id: Option<u32>,
+    pub collection_metadata_set: bool,
+    pub item_id: Option<u32>,
+    pub mint_receipt: Option<TxReceipt>,
+    pub item_metadata_set: bool,
+    #[serde(default)]
+    pub item_attributes_set: bool,
+    pub record_receipt: Option<TxReceipt>,
+    pub publisher: Option<String>,
+    #[serde(default)]
+    pub properties_lock_receipt: Option<TxReceipt>,
+    #[serde(default)]
+    pub transfer_lock_receipt: Option<TxReceipt>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl RegistrationJournal {
+    /// Open the journal for a content hash
+    ///
+    /// A fresh journal is started when none exists. An existing, unfinished
+    /// journal is only picked up with `resume`, so a rerun without it can't
+    /// silently mint a second NFT for the same content.
+    pub fn open(
+        journal_dir: &Path,
+        content_hash: &str,
+        title: &str,
+        url: &str,
+        resume: bool
+    ) -> Result<Self, EduNewsError> {
+        let path = journal_dir.join(format!("{}.json", content_hash));
+
+        if !path.exists() {
+            if resume {
+                return Err(EduNewsError::JournalNotFound { content_hash: content_hash.to_string() });
+            }
+            return Ok(RegistrationJournal {
+                content_hash: content_hash.to_string(),
+                title: title.to_string(),
+                url: url.to_string(),
+                path,
+                ..Default::default()
+            });
+        }
+
+        let data = fs::read_to_string(&path).map_err(|e| EduNewsError::FileRead {
+            path: path.clone(),
+            source: e,
+        })?;
+        let mut journal: RegistrationJournal = serde_json::from_str(&data)?;
+        journal.path = path;
+
+        if !resume && !journal.is_complete() {
+            return Err(EduNewsError::RegistrationPending {
+                content_hash: content_hash.to_string(),
+                path: journal.path,
+            });
+        }
+
+        // The NFT metadata may already carry the journaled title, so keep both chains consistent
+        if journal.title != title || journal.url != url {
+            return Err(EduNewsError::JournalMismatch {
+                content_hash: content_hash.to_string(),
+            });
+        }
+
+        Ok(journal)
+    }
+
+    /// Persist the journal after a completed step
+    pub fn save(&self) -> Result<(), EduNewsError> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Delete the journal once its registration has been rolled back
+    pub fn discard(&self) -> Result<(), EduNewsError> {
+        if self.path.exists() {
+            fs::remove_file(&self.path)?;
+        }
+        Ok(())
+    }
+
+    /// Whether every step, including the EduChain record, has completed
+    pub fn is_complete(&self) -> bool {
+        self.record_receipt.is_some()
+    }
+
+    /// Location of the journal file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "0x1111111111111111111111111111111111111111111111111111111111111111";
+
+    /// A fresh, empty journal directory for one test
+    fn journal_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("edunews-journal-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn receipt() -> TxReceipt {
+        TxReceipt {
+            extrinsic_hash: "0x01".to_string(),
+            block_hash: "0x02".to_string(),
+            block_number: 3,
+            extrinsic_index: 4,
+        }
+    }
+
+    /// Save a journal with the NFT minted but the EduChain record still missing
+    fn save_pending(dir: &Path) {
+        let mut journal = RegistrationJournal::open(dir, HASH, "Title", "https://example.com", false).unwrap();
+        journal.collection_id = Some(7);
+        journal.item_id = Some(9);
+        journal.mint_receipt = Some(receipt());
+        journal.save().unwrap();
+    }
+
+    #[test]
+    fn starts_a_fresh_journal() {
+        let dir = journal_dir("fresh");
+
+        let journal = RegistrationJournal::open(&dir, HASH, "Title", "https://example.com", false).unwrap();
+        assert_eq!(journal.content_hash, HASH);
+        assert_eq!(journal.collection_id, None);
+        assert!(!journal.is_complete());
+        assert_eq!(journal.path(), dir.join(format!("{}.json", HASH)));
+    }
+
+    #[test]
+    fn resume_requires_a_journal() {
+        let dir = journal_dir("missing");
+
+        let result = RegistrationJournal::open(&dir, HASH, "Title", "https://example.com", true);
+        assert!(matches!(result, Err(EduNewsError::JournalNotFound { .. })));
+    }
+
+    #[test]
+    fn refuses_pending_journal_without_resume() {
+        let dir = journal_dir("pending");
+        save_pending(&dir);
+
+        let result = RegistrationJournal::open(&dir, HASH, "Title", "https://example.com", false);
+        assert!(matches!(result, Err(EduNewsError::RegistrationPending { .. })));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn resumes_pending_journal() {
+        let dir = journal_dir("resume");
+        save_pending(&dir);
+
+        let journal = RegistrationJournal::open(&dir, HASH, "Title", "https://example.com", true).unwrap();
+        assert_eq!(journal.collection_id, Some(7));
+        assert_eq!(journal.item_id, Some(9));
+        assert!(journal.mint_receipt.is_some());
+        assert!(!journal.is_complete());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn refuses_resume_with_other_title_or_url() {
+        let dir = journal_dir("mismatch");
+        save_pending(&dir);
+
+        let other_title = RegistrationJournal::open(&dir, HASH, "Other", "https://example.com", true);
+        assert!(matches!(other_title, Err(EduNewsError::JournalMismatch { .. })));
+        let other_url = RegistrationJournal::open(&dir, HASH, "Title", "https://example.org", true);
+        assert!(matches!(other_url, Err(EduNewsError::JournalMismatch { .. })));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn opens_complete_journal_without_resume() {
+        let dir = journal_dir("complete");
+        let mut journal = RegistrationJournal::open(&dir, HASH, "Title", "https://example.com", false).unwrap();
+        journal.record_receipt = Some(receipt());
+        journal.save().unwrap();
+
+        let journal = RegistrationJournal::open(&dir, HASH, "Title", "https://example.com", false).unwrap();
+        assert!(journal.is_complete());
+
+        journal.discard().unwrap();
+        assert!(!journal.path().exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
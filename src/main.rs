@@ -1,17 +1,18 @@
-use clap::Parser;
+//! EduNews CLI - Subxt Multi-chain Demo
+//!
+//! This CLI demonstrates how to use Subxt to interact with multiple Polkadot parachains:
+//! - AssetHub: NFT creation for ownership proof
+//! - EduChain: Article registration with signatures  
+//! - PeopleHub: Identity verification
+//!
+//! Key learning points:
+//! - Multi-chain transaction coordination
+//! - Storage queries using generated metadata
+//! - Cryptographic signature handling
+//! - Cross-chain data linking
 
-/// EduNews CLI - Subxt Multi-chain Demo
-/// 
-/// This CLI demonstrates how to use Subxt to interact with multiple Polkadot parachains:
-/// - AssetHub: NFT creation for ownership proof
-/// - EduChain: Article registration with signatures  
-/// - PeopleHub: Identity verification
-/// 
-/// Key learning points:
-/// - Multi-chain transaction coordination
-/// - Storage queries using generated metadata
-/// - Cryptographic signature handling
-/// - Cross-chain data linking
+use clap::Parser;
+use std::process::ExitCode;
 
 mod commands;
 mod chains;
@@ -19,20 +20,39 @@ mod types;
 mod utils;
 mod error;
 mod config;
+mod journal;
 
 use commands::Commands;
 use error::EduNewsError;
 use types::Cli;
 
 #[tokio::main]
-async fn main() -> Result<(), EduNewsError> {
+async fn main() -> ExitCode {
     let cli = Cli::parse();
-    
+    let json_output = cli.json;
+
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            utils::print_error(&error, json_output);
+            ExitCode::from(error.exit_code())
+        }
+    }
+}
+
+/// Run the parsed subcommand
+async fn run(cli: Cli) -> Result<(), EduNewsError> {
     match cli.command {
         Commands::Register(args) => args.execute(cli.network, cli.json).await,
         Commands::Verify(args) => args.execute(cli.network, cli.json).await,
         Commands::List(args) => args.execute(cli.network, cli.json).await,
         Commands::Identity(args) => args.execute(cli.network, cli.json).await,
         Commands::Show(args) => args.execute(cli.network, cli.json).await,
+        Commands::Update(args) => args.execute(cli.network, cli.json).await,
+        Commands::History(args) => args.execute(cli.network, cli.json).await,
+        Commands::Transfer(args) => args.execute(cli.network, cli.json).await,
+        Commands::Retract(args) => args.execute(cli.network, cli.json).await,
+        Commands::Collection(args) => args.execute(cli.network, cli.json).await,
+        Commands::Publications(args) => args.execute(cli.network, cli.json).await,
     }
 }
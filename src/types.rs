@@ -34,27 +34,356 @@ pub struct Article {
     pub content_hash: String,
     pub publisher: String,
     pub timestamp: u64,
+    pub hash_algo: String,
+    pub signature_type: String,
+    pub signature: String,
     pub verified_nft: bool,
     pub verified_identity: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<VersionInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nft_metadata: Option<ArticleNftMetadata>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nft_owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retracted: Option<String>,
 }
 
 impl fmt::Display for Article {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(reason) = &self.retracted {
+            writeln!(f, "🚫 RETRACTED: {}", reason)?;
+        }
         write!(f, 
-            "Article Details\n  Collection ID: {}\n  Item ID: {}\n  Title: {}\n  URL: {}\n  Publisher: {}\n  Content Hash: {}\n  NFT Status: {}\n  Identity Status: {}\n  Timestamp: {}",
+            "Article Details\n  Collection ID: {}\n  Item ID: {}\n  Title: {}\n  URL: {}\n  Publisher: {}\n  Content Hash: {}\n  Hash Algorithm: {}\n  Signature ({}): {}\n  NFT Status: {}\n  Identity Status: {}\n  Timestamp: {}",
             self.collection_id,
             self.item_id,
             self.title,
             self.url,
             self.publisher,
             self.content_hash,
+            self.hash_algo,
+            self.signature_type,
+            self.signature,
             if self.verified_nft { "✅ Verified" } else { "❌ Not Found" },
             if self.verified_identity { "✅ Verified" } else { "❌ Unverified" },
             self.timestamp
+        )?;
+        if let Some(nft_owner) = &self.nft_owner {
+            if *nft_owner == self.publisher {
+                write!(f, "\n  NFT Owner: {} (publisher)", nft_owner)?;
+            } else {
+                write!(f, "\n  NFT Owner: {} (⚠️ transferred from the publisher)", nft_owner)?;
+            }
+        }
+        if let Some(version) = &self.version {
+            write!(f, "\n{}", version)?;
+        }
+        if let Some(nft_metadata) = &self.nft_metadata {
+            write!(f, "\n{}", nft_metadata)?;
+        }
+        Ok(())
+    }
+}
+
+/// Position of a single version within its article's lineage
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VersionInfo {
+    pub anchor: String,
+    pub latest_hash: String,
+    pub version: u32,
+    pub total_versions: u32,
+    pub is_latest: bool,
+}
+
+impl fmt::Display for VersionInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "  Anchor: {}\n  Version: {} of {}\n  Version Status: {}",
+            self.anchor,
+            self.version,
+            self.total_versions,
+            if self.is_latest {
+                "✅ Latest".to_string()
+            } else {
+                format!("⚠️ Superseded by {}", self.latest_hash)
+            }
         )
     }
 }
 
+/// Current version of the NFT metadata schema written by `register`
+pub const ARTICLE_NFT_SCHEMA_VERSION: u32 = 2;
+
+/// Article metadata stored on the AssetHub NFT (`ItemMetadataOf`)
+///
+/// Keys are kept to a single letter because the whole document must fit
+/// pallet_nfts' StringLimit. Since schema version 2 the canonical URL and
+/// EduChain genesis are left to the item attributes of the same names, so
+/// most of the limit is left for the title. Version 1 documents that carry
+/// them, and the pre-schema format (`{"title","content_hash"}`, parsed as
+/// schema version 0), still parse.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArticleNftMetadata {
+    /// Schema version
+    #[serde(rename = "v", default)]
+    pub schema_version: u32,
+    /// Article title
+    #[serde(rename = "t", alias = "title")]
+    pub title: String,
+    /// Blake2b-256 content hash (0x-prefixed)
+    #[serde(rename = "h", alias = "content_hash")]
+    pub content_hash: String,
+    /// Canonical article URL, only stored in the document by schema version 1
+    #[serde(rename = "u", default, skip_serializing_if = "Option::is_none")]
+    pub canonical_url: Option<String>,
+    /// Genesis hash of the EduChain holding the article record, only stored
+    /// in the document by schema version 1
+    #[serde(rename = "g", default, skip_serializing_if = "Option::is_none")]
+    pub educhain_genesis: Option<String>,
+    /// EduChain anchor hash linking to the article's version history,
+    /// omitted when it is the content hash itself (the first version)
+    #[serde(rename = "a", default, skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
+}
+
+impl ArticleNftMetadata {
+    /// Parse metadata bytes read back from AssetHub
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        serde_json::from_slice(data).ok()
+    }
+
+    /// The EduChain anchor this NFT links to
+    pub fn anchor_hash(&self) -> &str {
+        self.anchor.as_deref().unwrap_or(&self.content_hash)
+    }
+}
+
+impl fmt::Display for ArticleNftMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "  NFT Metadata (schema v{})\n    Title: {}\n    Content Hash: {}\n    Canonical URL: {}\n    EduChain Genesis: {}\n    Anchor: {}",
+            self.schema_version,
+            self.title,
+            self.content_hash,
+            self.canonical_url.as_deref().unwrap_or("-"),
+            self.educhain_genesis.as_deref().unwrap_or("-"),
+            self.anchor_hash()
+        )
+    }
+}
+
+/// Article provenance stored as pallet_nfts attributes on the item
+///
+/// Unlike the metadata blob, each attribute can be queried on its own by
+/// indexers and marketplaces.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct NftAttributes {
+    pub collection_id: u32,
+    pub item_id: u32,
+    pub content_hash: Option<String>,
+    pub hash_algo: Option<String>,
+    pub educhain_genesis: Option<String>,
+    pub canonical_url: Option<String>,
+    pub word_count: Option<u32>,
+    /// Retraction reason, set by `edunews retract`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retracted: Option<String>,
+}
+
+impl NftAttributes {
+    /// Key/value pairs of the attributes that are set, as stored on chain
+    pub fn entries(&self) -> Vec<(&'static str, String)> {
+        let mut entries = Vec::new();
+        if let Some(content_hash) = &self.content_hash {
+            entries.push(("content_hash", content_hash.clone()));
+        }
+        if let Some(hash_algo) = &self.hash_algo {
+            entries.push(("hash_algo", hash_algo.clone()));
+        }
+        if let Some(educhain_genesis) = &self.educhain_genesis {
+            entries.push(("educhain_genesis", educhain_genesis.clone()));
+        }
+        if let Some(canonical_url) = &self.canonical_url {
+            entries.push(("canonical_url", canonical_url.clone()));
+        }
+        if let Some(word_count) = self.word_count {
+            entries.push(("word_count", word_count.to_string()));
+        }
+        if let Some(retracted) = &self.retracted {
+            entries.push(("retracted", retracted.clone()));
+        }
+        entries
+    }
+
+    /// Set an attribute from its on-chain key and value; unknown keys are ignored
+    pub fn set(&mut self, key: &str, value: String) {
+        match key {
+            "content_hash" => self.content_hash = Some(value),
+            "hash_algo" => self.hash_algo = Some(value),
+            "educhain_genesis" => self.educhain_genesis = Some(value),
+            "canonical_url" => self.canonical_url = Some(value),
+            "word_count" => self.word_count = value.parse().ok(),
+            "retracted" => self.retracted = Some(value),
+            _ => {}
+        }
+    }
+}
+
+impl fmt::Display for NftAttributes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+        write!(f,
+            "NFT Attributes\n  Collection ID: {}\n  Item ID: {}\n  Content Hash: {}\n  Hash Algorithm: {}\n  EduChain Genesis: {}\n  Canonical URL: {}\n  Word Count: {}",
+            self.collection_id,
+            self.item_id,
+            show(&self.content_hash),
+            show(&self.hash_algo),
+            show(&self.educhain_genesis),
+            show(&self.canonical_url),
+            show(&self.word_count.map(|count| count.to_string()))
+        )?;
+        if let Some(reason) = &self.retracted {
+            write!(f, "\n  🚫 RETRACTED: {}", reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// Current version of the collection metadata schema
+pub const PUBLICATION_SCHEMA_VERSION: u32 = 1;
+
+/// Publication profile of an outlet, stored as its collection metadata
+///
+/// Like `ArticleNftMetadata`, keys are kept short to fit StringLimit; the
+/// long names are accepted when reading a profile file. `max_supply` and
+/// `transferable` are applied to the collection config, not stored.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicationProfile {
+    /// Schema version
+    #[serde(rename = "v", default)]
+    pub schema_version: u32,
+    /// Publication name
+    #[serde(rename = "n", alias = "name")]
+    pub name: String,
+    /// Publication website
+    #[serde(rename = "w", alias = "website", default, skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    /// Hash of the publication logo
+    #[serde(rename = "l", alias = "logo_hash", default, skip_serializing_if = "Option::is_none")]
+    pub logo_hash: Option<String>,
+    /// Short description of the publication
+    #[serde(rename = "d", alias = "description", default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Maximum number of articles in the collection
+    #[serde(default, skip_serializing)]
+    pub max_supply: Option<u32>,
+    /// Whether newly minted article NFTs are transferable
+    #[serde(default, skip_serializing)]
+    pub transferable: Option<bool>,
+}
+
+impl PublicationProfile {
+    /// Parse collection metadata bytes read back from AssetHub
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        serde_json::from_slice(data).ok()
+    }
+}
+
+/// Profile fields to change, as read from a profile file or given as flags
+///
+/// Every field is optional, so a file only changes the fields it names and
+/// the rest of the profile is kept.
+#[derive(Debug, Default, Deserialize)]
+pub struct ProfileOverlay {
+    #[serde(rename = "n", alias = "name", default)]
+    pub name: Option<String>,
+    #[serde(rename = "w", alias = "website", default)]
+    pub website: Option<String>,
+    #[serde(rename = "l", alias = "logo_hash", default)]
+    pub logo_hash: Option<String>,
+    #[serde(rename = "d", alias = "description", default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub max_supply: Option<u32>,
+    #[serde(default)]
+    pub transferable: Option<bool>,
+}
+
+impl ProfileOverlay {
+    /// Overwrite the profile's fields with the ones that are set
+    pub fn apply(self, profile: &mut PublicationProfile) {
+        if let Some(name) = self.name {
+            profile.name = name;
+        }
+        if let Some(website) = self.website {
+            profile.website = Some(website);
+        }
+        if let Some(logo_hash) = self.logo_hash {
+            profile.logo_hash = Some(logo_hash);
+        }
+        if let Some(description) = self.description {
+            profile.description = Some(description);
+        }
+        if let Some(max_supply) = self.max_supply {
+            profile.max_supply = Some(max_supply);
+        }
+        if let Some(transferable) = self.transferable {
+            profile.transferable = Some(transferable);
+        }
+    }
+}
+
+/// An AssetHub collection with its config and publication profile
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CollectionInfo {
+    pub collection_id: u32,
+    pub owner: String,
+    pub items: u32,
+    pub max_supply: Option<u32>,
+    pub transferable: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<PublicationProfile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_metadata: Option<String>,
+}
+
+impl CollectionInfo {
+    /// Name of the publication, including plain text metadata of older collections
+    pub fn publication_name(&self) -> Option<&str> {
+        self.profile.as_ref().map(|profile| profile.name.as_str()).or(self.raw_metadata.as_deref())
+    }
+}
+
+impl fmt::Display for CollectionInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "Collection Details\n  Collection ID: {}\n  Owner: {}\n  Articles: {}\n  Max Supply: {}\n  New Articles Transferable: {}",
+            self.collection_id,
+            self.owner,
+            self.items,
+            self.max_supply.map_or("Unlimited".to_string(), |max_supply| max_supply.to_string()),
+            if self.transferable { "✅ Yes" } else { "❌ No" }
+        )?;
+        if let Some(profile) = &self.profile {
+            write!(f, "\n  Publication: {}", profile.name)?;
+            if let Some(website) = &profile.website {
+                write!(f, "\n  Website: {}", website)?;
+            }
+            if let Some(logo_hash) = &profile.logo_hash {
+                write!(f, "\n  Logo Hash: {}", logo_hash)?;
+            }
+            if let Some(description) = &profile.description {
+                write!(f, "\n  Description: {}", description)?;
+            }
+        }
+        if let Some(raw_metadata) = &self.raw_metadata {
+            write!(f, "\n  Metadata: {}", raw_metadata)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PublisherIdentity {
     pub address: String,
@@ -82,10 +411,31 @@ pub struct VerificationResult {
     pub article_exists: bool,
     pub nft_exists: bool,
     pub publisher_verified: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<VersionInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_match: Option<ContentMatch>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_valid: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nft_link: Option<NftLink>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_matches_publisher: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retracted: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lock_state: Option<LockState>,
 }
 
 impl fmt::Display for VerificationResult {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(reason) = &self.retracted {
+            writeln!(f, "🚫 RETRACTED: {}", reason)?;
+        }
         write!(f,
             "Verification Result\n  Collection ID: {}\n  Item ID: {}\n  Article Exists: {}\n  NFT Exists: {}\n  Publisher Verified: {}",
             self.collection_id,
@@ -93,6 +443,116 @@ impl fmt::Display for VerificationResult {
             if self.article_exists { "✅ Yes" } else { "❌ No" },
             if self.nft_exists { "✅ Yes" } else { "❌ No" },
             if self.publisher_verified { "✅ Yes" } else { "❌ No" }
+        )?;
+        if let Some(content_hash) = &self.content_hash {
+            write!(f, "\n  Content Hash: {}", content_hash)?;
+        }
+        if let Some(version) = &self.version {
+            write!(f, "\n{}", version)?;
+        }
+        if let Some(content_match) = &self.content_match {
+            write!(f, "\n  Local Content: {}", content_match)?;
+        }
+        if let Some(signature_valid) = self.signature_valid {
+            write!(f, "\n  Publisher Signature: {}", if signature_valid { "✅ Valid" } else { "❌ Invalid" })?;
+        }
+        if let Some(nft_link) = &self.nft_link {
+            write!(f, "\n  NFT Link: {}", nft_link)?;
+        }
+        if let Some(current_owner) = &self.current_owner {
+            write!(f, "\n  NFT Owner: {}", current_owner)?;
+        }
+        if let Some(lock_state) = &self.lock_state {
+            write!(f, "\n{}", lock_state)?;
+        }
+        if let Some(owner_matches_publisher) = self.owner_matches_publisher {
+            write!(f,
+                "\n  Owned by Publisher: {}",
+                if owner_matches_publisher { "✅ Yes" } else { "⚠️ No (NFT was transferred)" }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Which properties of an NFT are permanently locked (from `ItemConfigOf`)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LockState {
+    pub metadata_locked: bool,
+    pub attributes_locked: bool,
+    pub transfer_locked: bool,
+}
+
+impl fmt::Display for LockState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = |locked: bool| if locked { "🔒 Locked" } else { "🔓 Unlocked" };
+        write!(f,
+            "  NFT Metadata: {}\n  NFT Attributes: {}\n  NFT Transfer: {}",
+            status(self.metadata_locked),
+            status(self.attributes_locked),
+            status(self.transfer_locked)
+        )
+    }
+}
+
+/// Outcome of cross-checking the NFT's metadata against the EduChain record
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum NftLink {
+    /// The metadata names this article's content hash and title
+    Linked,
+    /// The NFT carries no parseable article metadata
+    MissingMetadata,
+    /// The metadata disagrees with EduChain on the listed fields
+    Mismatch { fields: Vec<String> },
+}
+
+impl fmt::Display for NftLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NftLink::Linked => write!(f, "✅ Metadata matches the EduChain record"),
+            NftLink::MissingMetadata => write!(f, "❌ NFT has no article metadata"),
+            NftLink::Mismatch { fields } => write!(f, "❌ Metadata differs from the EduChain record ({})", fields.join(", ")),
+        }
+    }
+}
+
+/// Outcome of comparing local article content against the on-chain lineage
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentMatch {
+    MatchLatest,
+    MatchOlderVersion,
+    NoMatch,
+}
+
+impl fmt::Display for ContentMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContentMatch::MatchLatest => write!(f, "✅ Matches latest version"),
+            ContentMatch::MatchOlderVersion => write!(f, "⚠️ Matches an older version"),
+            ContentMatch::NoMatch => write!(f, "❌ No match (content differs from every registered version)"),
+        }
+    }
+}
+
+/// Where a finalized extrinsic landed, for linking to block explorers
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TxReceipt {
+    pub extrinsic_hash: String,
+    pub block_hash: String,
+    pub block_number: u64,
+    pub extrinsic_index: u32,
+}
+
+impl fmt::Display for TxReceipt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "{} (block #{}, extrinsic {}, block hash {})",
+            self.extrinsic_hash,
+            self.block_number,
+            self.extrinsic_index,
+            self.block_hash
         )
     }
 }
@@ -103,16 +563,478 @@ pub struct RegistrationResult {
     pub item_id: u32,
     pub tx_hash: String,
     pub content_hash: String,
+    pub publisher: String,
+    pub assethub_mint: TxReceipt,
+    pub educhain_record: TxReceipt,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties_locked: Option<TxReceipt>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer_locked: Option<TxReceipt>,
 }
 
 impl fmt::Display for RegistrationResult {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f,
-            "Registration Successful\n  Collection ID: {}\n  Item ID: {}\n  Transaction Hash: {}\n  Content Hash: {}",
+            "Registration Successful\n  Collection ID: {}\n  Item ID: {}\n  Transaction Hash: {}\n  Content Hash: {}\n  Publisher: {}\n  AssetHub Mint: {}\n  EduChain Record: {}",
             self.collection_id,
             self.item_id,
             self.tx_hash,
-            self.content_hash
+            self.content_hash,
+            self.publisher,
+            self.assethub_mint,
+            self.educhain_record
+        )?;
+        if let Some(receipt) = &self.properties_locked {
+            write!(f, "\n  Properties Locked: {}", receipt)?;
+        }
+        if let Some(receipt) = &self.transfer_locked {
+            write!(f, "\n  Transfer Locked: {}", receipt)?;
+        }
+        Ok(())
+    }
+}
+
+/// A single fee or deposit in a registration plan, in planck
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlannedCost {
+    pub description: String,
+    pub amount: u128,
+}
+
+/// Everything a registration would pay on one chain
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChainPlan {
+    pub chain: String,
+    pub fees: Vec<PlannedCost>,
+    pub deposits: Vec<PlannedCost>,
+    pub total_fees: u128,
+    pub total_deposits: u128,
+}
+
+impl ChainPlan {
+    pub fn new(chain: &str, fees: Vec<PlannedCost>, deposits: Vec<PlannedCost>) -> Self {
+        ChainPlan {
+            chain: chain.to_string(),
+            total_fees: fees.iter().map(|fee| fee.amount).sum(),
+            total_deposits: deposits.iter().map(|deposit| deposit.amount).sum(),
+            fees,
+            deposits,
+        }
+    }
+
+    /// Fees plus deposits
+    pub fn total(&self) -> u128 {
+        self.total_fees + self.total_deposits
+    }
+}
+
+impl fmt::Display for ChainPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "  {}", self.chain)?;
+        for fee in &self.fees {
+            write!(f, "\n    Fee: {} — {}", fee.description, fee.amount)?;
+        }
+        for deposit in &self.deposits {
+            write!(f, "\n    Deposit: {} — {}", deposit.description, deposit.amount)?;
+        }
+        write!(f,
+            "\n    Total: {} ({} fees + {} refundable deposits)",
+            self.total(),
+            self.total_fees,
+            self.total_deposits
+        )
+    }
+}
+
+/// What a registration would submit and pay, without submitting anything
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegistrationPlan {
+    pub collection_id: u32,
+    pub item_id: u32,
+    pub creates_collection: bool,
+    pub assethub: ChainPlan,
+    pub educhain: ChainPlan,
+}
+
+impl fmt::Display for RegistrationPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "Registration Plan (dry run, amounts in planck)\n  Collection ID: {}{}\n  Item ID: {}\n{}\n{}",
+            self.collection_id,
+            if self.creates_collection { " (new)" } else { "" },
+            self.item_id,
+            self.assethub,
+            self.educhain
         )
     }
-}
\ No newline at end of file
+}
+
+/// Whether an account can pay for its part of a registration on one chain
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BalanceCheck {
+    pub chain: String,
+    pub free: u128,
+    pub fees: u128,
+    pub deposits: u128,
+    pub existential_deposit: u128,
+    pub required: u128,
+    pub shortfall: u128,
+}
+
+impl BalanceCheck {
+    /// Compare a free balance to a chain's planned costs
+    ///
+    /// The existential deposit is kept on top of the costs, so paying them
+    /// never reaps the account.
+    pub fn new(plan: &ChainPlan, free: u128, existential_deposit: u128) -> Self {
+        let required = plan.total() + existential_deposit;
+        BalanceCheck {
+            chain: plan.chain.clone(),
+            free,
+            fees: plan.total_fees,
+            deposits: plan.total_deposits,
+            existential_deposit,
+            required,
+            shortfall: required.saturating_sub(free),
+        }
+    }
+}
+
+impl fmt::Display for BalanceCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "  {}\n    Free: {}\n    Required: {} ({} fees + {} deposits + {} existential deposit)",
+            self.chain,
+            self.free,
+            self.required,
+            self.fees,
+            self.deposits,
+            self.existential_deposit
+        )?;
+        if self.shortfall > 0 {
+            write!(f, "\n    ❌ Short by {}", self.shortfall)?;
+        }
+        Ok(())
+    }
+}
+
+/// Balance checks of a registration's signer on every chain it pays on
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BalanceReport {
+    pub checks: Vec<BalanceCheck>,
+}
+
+impl BalanceReport {
+    /// Chains where the free balance doesn't cover the planned costs
+    pub fn short_chains(&self) -> Vec<String> {
+        self.checks
+            .iter()
+            .filter(|check| check.shortfall > 0)
+            .map(|check| check.chain.clone())
+            .collect()
+    }
+}
+
+impl fmt::Display for BalanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Balance Check (amounts in planck)")?;
+        for check in &self.checks {
+            write!(f, "\n{}", check)?;
+        }
+        Ok(())
+    }
+}
+
+/// What was undone on AssetHub after a failed EduChain registration
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RollbackReport {
+    pub collection_id: u32,
+    pub item_id: u32,
+    pub attributes_cleared: Vec<String>,
+    pub metadata_cleared: Option<TxReceipt>,
+    pub item_burned: Option<TxReceipt>,
+    pub errors: Vec<String>,
+}
+
+impl fmt::Display for RollbackReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "Rollback Report\n  Collection ID: {}\n  Item ID: {}",
+            self.collection_id,
+            self.item_id
+        )?;
+        if !self.attributes_cleared.is_empty() {
+            write!(f, "\n  Attributes Cleared: ✅ {}", self.attributes_cleared.join(", "))?;
+        }
+        match &self.metadata_cleared {
+            Some(receipt) => write!(f, "\n  Metadata Cleared: ✅ {}", receipt)?,
+            None => write!(f, "\n  Metadata Cleared: ❌ No")?,
+        }
+        match &self.item_burned {
+            Some(receipt) => write!(f, "\n  Item Burned: ✅ {}", receipt)?,
+            None => write!(f, "\n  Item Burned: ❌ No")?,
+        }
+        for error in &self.errors {
+            write!(f, "\n  Error: {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransferResult {
+    pub collection_id: u32,
+    pub item_id: u32,
+    pub from: String,
+    pub to: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+    pub receipt: TxReceipt,
+}
+
+impl fmt::Display for TransferResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "Transfer Successful\n  Collection ID: {}\n  Item ID: {}\n  From: {}\n  To: {}",
+            self.collection_id,
+            self.item_id,
+            self.from,
+            self.to
+        )?;
+        if let Some(publisher) = &self.publisher {
+            write!(f, "\n  EduChain Publisher: {}", publisher)?;
+        }
+        write!(f, "\n  Transaction: {}", self.receipt)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RetractResult {
+    pub collection_id: u32,
+    pub item_id: u32,
+    pub reason: String,
+    pub retraction: TxReceipt,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub final_version: Option<UpdateResult>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_burned: Option<TxReceipt>,
+}
+
+impl fmt::Display for RetractResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "Retraction Successful\n  Collection ID: {}\n  Item ID: {}\n  Reason: {}\n  Transaction: {}",
+            self.collection_id,
+            self.item_id,
+            self.reason,
+            self.retraction
+        )?;
+        if let Some(final_version) = &self.final_version {
+            write!(f,
+                "\n  Final Version: {} ({})\n  Final Version Transaction: {}",
+                final_version.version,
+                final_version.content_hash,
+                final_version.receipt
+            )?;
+        }
+        if let Some(receipt) = &self.item_burned {
+            write!(f, "\n  Item Burned: ✅ {}", receipt)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateResult {
+    pub collection_id: u32,
+    pub item_id: u32,
+    pub previous_hash: String,
+    pub content_hash: String,
+    pub version: u32,
+    pub receipt: TxReceipt,
+}
+
+impl fmt::Display for UpdateResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "Update Successful\n  Collection ID: {}\n  Item ID: {}\n  Previous Hash: {}\n  Content Hash: {}\n  Version: {}\n  Transaction: {}",
+            self.collection_id,
+            self.item_id,
+            self.previous_hash,
+            self.content_hash,
+            self.version,
+            self.receipt
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ArticleVersion {
+    pub version: u32,
+    pub content_hash: String,
+    pub title: String,
+    pub url: String,
+    pub publisher: String,
+    pub last_updated_at: u32,
+}
+
+impl fmt::Display for ArticleVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "Version {}\n  Content Hash: {}\n  Title: {}\n  URL: {}\n  Publisher: {}\n  Last Updated At: block {}",
+            self.version,
+            self.content_hash,
+            self.title,
+            self.url,
+            self.publisher,
+            self.last_updated_at
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ArticleHistory {
+    pub anchor: String,
+    pub collection_id: u32,
+    pub item_id: u32,
+    pub versions: Vec<ArticleVersion>,
+}
+
+impl fmt::Display for ArticleHistory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+            "Article History\n  Anchor: {}\n  Collection ID: {}\n  Item ID: {}\n  Versions: {}",
+            self.anchor,
+            self.collection_id,
+            self.item_id,
+            self.versions.len()
+        )?;
+        for version in &self.versions {
+            write!(f, "\n\n{}", version)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_metadata(anchor: Option<&str>) -> ArticleNftMetadata {
+        ArticleNftMetadata {
+            schema_version: ARTICLE_NFT_SCHEMA_VERSION,
+            title: "Tidal energy in the Baltic".to_string(),
+            content_hash: format!("0x{}", "ab".repeat(32)),
+            canonical_url: None,
+            educhain_genesis: None,
+            anchor: anchor.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn parses_legacy_metadata_as_schema_zero() {
+        let legacy = br#"{"title":"Old article","content_hash":"0x1234"}"#;
+        let metadata = ArticleNftMetadata::parse(legacy).unwrap();
+
+        assert_eq!(metadata.schema_version, 0);
+        assert_eq!(metadata.title, "Old article");
+        assert_eq!(metadata.content_hash, "0x1234");
+        assert_eq!(metadata.canonical_url, None);
+        assert_eq!(metadata.educhain_genesis, None);
+        assert_eq!(metadata.anchor, None);
+        assert_eq!(metadata.anchor_hash(), "0x1234");
+    }
+
+    #[test]
+    fn round_trips_first_version_metadata() {
+        let metadata = sample_metadata(None);
+        let data = serde_json::to_vec(&metadata).unwrap();
+
+        assert!(!String::from_utf8_lossy(&data).contains("\"a\""));
+        let parsed = ArticleNftMetadata::parse(&data).unwrap();
+        assert_eq!(parsed, metadata);
+        assert_eq!(parsed.anchor_hash(), metadata.content_hash);
+    }
+
+    #[test]
+    fn round_trips_later_version_metadata_with_anchor() {
+        let anchor = format!("0x{}", "cd".repeat(32));
+        let metadata = sample_metadata(Some(&anchor));
+        let data = serde_json::to_vec(&metadata).unwrap();
+
+        let parsed = ArticleNftMetadata::parse(&data).unwrap();
+        assert_eq!(parsed, metadata);
+        assert_eq!(parsed.schema_version, 2);
+        assert_eq!(parsed.anchor_hash(), anchor);
+    }
+
+    #[test]
+    fn parses_schema_one_metadata_with_url_and_genesis() {
+        let data = br#"{"v":1,"t":"Tidal energy","h":"0x1234","u":"https://news.example/tidal","g":"0x0101"}"#;
+        let metadata = ArticleNftMetadata::parse(data).unwrap();
+
+        assert_eq!(metadata.schema_version, 1);
+        assert_eq!(metadata.canonical_url.as_deref(), Some("https://news.example/tidal"));
+        assert_eq!(metadata.educhain_genesis.as_deref(), Some("0x0101"));
+    }
+
+    #[test]
+    fn leaves_most_of_the_string_limit_to_the_title() {
+        // AssetHub's StringLimit is 256 bytes; without the title, a first
+        // version only spends its schema version and content hash
+        let mut metadata = sample_metadata(None);
+        metadata.title = String::new();
+        let fixed = serde_json::to_vec(&metadata).unwrap().len();
+
+        assert_eq!(fixed, 87);
+    }
+
+    fn cost(description: &str, amount: u128) -> PlannedCost {
+        PlannedCost { description: description.to_string(), amount }
+    }
+
+    fn assethub_plan() -> ChainPlan {
+        ChainPlan::new(
+            "AssetHub",
+            vec![cost("Batch fee", 300)],
+            vec![cost("Item deposit", 1_000), cost("Metadata deposit", 200)]
+        )
+    }
+
+    #[test]
+    fn requires_costs_plus_existential_deposit() {
+        let check = BalanceCheck::new(&assethub_plan(), 1_000, 100);
+
+        assert_eq!(check.fees, 300);
+        assert_eq!(check.deposits, 1_200);
+        assert_eq!(check.required, 1_600);
+        assert_eq!(check.shortfall, 600);
+    }
+
+    #[test]
+    fn has_no_shortfall_when_the_balance_covers_the_costs() {
+        // Exactly enough leaves the existential deposit behind
+        assert_eq!(BalanceCheck::new(&assethub_plan(), 1_600, 100).shortfall, 0);
+        assert_eq!(BalanceCheck::new(&assethub_plan(), 1_599, 100).shortfall, 1);
+        assert_eq!(BalanceCheck::new(&assethub_plan(), u128::MAX, 100).shortfall, 0);
+    }
+
+    #[test]
+    fn reports_only_the_chains_that_fall_short() {
+        let educhain_plan = ChainPlan::new("EduChain", vec![cost("record_article fee", 50)], vec![]);
+        let report = BalanceReport {
+            checks: vec![
+                BalanceCheck::new(&assethub_plan(), 0, 100),
+                BalanceCheck::new(&educhain_plan, 500, 1)
+            ],
+        };
+
+        assert_eq!(report.short_chains(), vec!["AssetHub".to_string()]);
+    }
+
+    #[test]
+    fn rejects_metadata_that_is_not_an_article() {
+        assert_eq!(ArticleNftMetadata::parse(b"not json"), None);
+        assert_eq!(ArticleNftMetadata::parse(br#"{"name":"Some NFT"}"#), None);
+    }
+}
@@ -1,6 +1,9 @@
 use blake2::{Blake2b, Digest};
 use colored::*;
-use subxt_signer::{sr25519::Keypair, SecretUri};
+use subxt::utils::AccountId32;
+use subxt_signer::{sr25519::{self, Keypair, PublicKey, Signature}, SecretUri};
+use std::fs;
+use std::path::PathBuf;
 use std::str::FromStr;
 
 use crate::error::EduNewsError;
@@ -12,6 +15,69 @@ pub fn hash_content(content: &str) -> String {
     hex::encode(hasher.finalize())
 }
 
+/// Load article content from an inline string or a file
+pub fn load_content(content: &Option<String>, content_file: &Option<PathBuf>) -> Result<String, EduNewsError> {
+    match (content, content_file) {
+        (Some(content), None) => Ok(content.clone()),
+        (None, Some(path)) =>
+            fs::read_to_string(path).map_err(|e| EduNewsError::FileRead {
+                path: path.clone(),
+                source: e,
+            }),
+        (None, None) => Err(EduNewsError::NoContentProvided),
+        (Some(_), Some(_)) => unreachable!(), // clap handles this validation
+    }
+}
+
+/// Wrap a content hash in `<Bytes>...</Bytes>` as expected by the News pallet
+///
+/// The wrapping matches what PAPI, Polkadot-JS and browser extensions do when
+/// signing raw bytes, so signatures from any of those tools verify the same way.
+pub fn wrap_content_hash(content_hash_raw: &str) -> Result<Vec<u8>, EduNewsError> {
+    // Convert hex string back to bytes for signing (without 0x prefix)
+    let content_hash_bytes = hex
+        ::decode(content_hash_raw.trim_start_matches("0x"))
+        .map_err(|_| EduNewsError::InvalidContentHash {
+            hash: content_hash_raw.to_string(),
+        })?;
+
+    let mut wrapped_msg = b"<Bytes>".to_vec();
+    wrapped_msg.extend_from_slice(&content_hash_bytes);
+    wrapped_msg.extend_from_slice(b"</Bytes>");
+    Ok(wrapped_msg)
+}
+
+/// Sign a content hash with the publisher keypair using the `<Bytes>` wrapping
+pub fn sign_content_hash(keypair: &Keypair, content_hash_raw: &str) -> Result<Signature, EduNewsError> {
+    let wrapped_msg = wrap_content_hash(content_hash_raw)?;
+    Ok(keypair.sign(&wrapped_msg))
+}
+
+/// Re-check a publisher's sr25519 signature over a content hash, offline
+///
+/// The signature is accepted over the `<Bytes>`-wrapped hash (what this CLI
+/// and browser wallets produce) or over the raw hash bytes.
+pub fn verify_content_signature(
+    publisher: &str,
+    content_hash: &str,
+    signature: &str
+) -> Result<bool, EduNewsError> {
+    let account = AccountId32::from_str(publisher)
+        .map_err(|_| EduNewsError::InvalidAddress { address: publisher.to_string() })?;
+
+    let signature_bytes: [u8; 64] = hex
+        ::decode(signature.trim_start_matches("0x"))?
+        .try_into()
+        .map_err(|_| EduNewsError::InvalidSignature { signature: signature.to_string() })?;
+    let signature = Signature(signature_bytes);
+    let public_key = PublicKey(account.0);
+
+    let wrapped_msg = wrap_content_hash(content_hash)?;
+    let raw_msg = &wrapped_msg[b"<Bytes>".len()..wrapped_msg.len() - b"</Bytes>".len()];
+
+    Ok(sr25519::verify(&signature, &wrapped_msg, &public_key) || sr25519::verify(&signature, raw_msg, &public_key))
+}
+
 /// Create keypair from mnemonic phrase
 pub fn create_keypair_from_mnemonic(mnemonic: &str) -> Result<Keypair, EduNewsError> {
     let uri = SecretUri::from_str(mnemonic)
@@ -21,14 +87,36 @@ pub fn create_keypair_from_mnemonic(mnemonic: &str) -> Result<Keypair, EduNewsEr
         .map_err(|_| EduNewsError::InvalidMnemonic)
 }
 
+// Status messages go to stderr, so stdout only carries command output
+// (and stays valid JSON under --json)
+
 /// Print colored success message
 pub fn print_success(message: &str) {
-    println!("{} {}", "✅".green(), message.green());
+    eprintln!("{} {}", "✅".green(), message.green());
 }
 
 /// Print colored info message
 pub fn print_info(message: &str) {
-    println!("{} {}", "ℹ️".blue(), message.blue());
+    eprintln!("{} {}", "ℹ️".blue(), message.blue());
+}
+
+/// Print colored warning message
+pub fn print_warning(message: &str) {
+    eprintln!("{} {}", "⚠️".yellow(), message.yellow());
+}
+
+/// Print an error to stderr, as `{"error", "message", "exit_code"}` JSON under --json
+pub fn print_error(error: &EduNewsError, as_json: bool) {
+    if as_json {
+        let report = serde_json::json!({
+            "error": error.kind(),
+            "message": error.to_string(),
+            "exit_code": error.exit_code(),
+        });
+        eprintln!("{}", report);
+    } else {
+        eprintln!("{} {}", "❌".red(), error.to_string().red());
+    }
 }
 
 /// Format output as JSON or human-readable
@@ -38,4 +126,88 @@ pub fn format_output<T: serde::Serialize + std::fmt::Display>(data: &T, as_json:
     } else {
         Ok(format!("{}", data))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alice() -> (Keypair, String) {
+        let keypair = create_keypair_from_mnemonic("//Alice").unwrap();
+        let address = AccountId32::from(keypair.public_key()).to_string();
+        (keypair, address)
+    }
+
+    #[test]
+    fn accepts_wrapped_signature() {
+        let (keypair, address) = alice();
+        let content_hash = hash_content("article body");
+        let signature = sign_content_hash(&keypair, &content_hash).unwrap();
+
+        let valid = verify_content_signature(&address, &content_hash, &hex::encode(signature.0)).unwrap();
+        assert!(valid);
+    }
+
+    #[test]
+    fn accepts_raw_signature() {
+        let (keypair, address) = alice();
+        let content_hash = hash_content("article body");
+        let signature = keypair.sign(&hex::decode(&content_hash).unwrap());
+
+        let valid = verify_content_signature(&address, &format!("0x{}", content_hash), &hex::encode(signature.0)).unwrap();
+        assert!(valid);
+    }
+
+    #[test]
+    fn rejects_signature_over_other_content() {
+        let (keypair, address) = alice();
+        let signed_hash = hash_content("article body");
+        let other_hash = hash_content("tampered body");
+
+        let wrapped = sign_content_hash(&keypair, &signed_hash).unwrap();
+        let raw = keypair.sign(&hex::decode(&signed_hash).unwrap());
+
+        assert!(!verify_content_signature(&address, &other_hash, &hex::encode(wrapped.0)).unwrap());
+        assert!(!verify_content_signature(&address, &other_hash, &hex::encode(raw.0)).unwrap());
+    }
+
+    #[test]
+    fn rejects_tampered_signature_bytes() {
+        let (keypair, address) = alice();
+        let content_hash = hash_content("article body");
+
+        let mut wrapped = sign_content_hash(&keypair, &content_hash).unwrap().0;
+        wrapped[0] ^= 1;
+        let mut raw = keypair.sign(&hex::decode(&content_hash).unwrap()).0;
+        raw[0] ^= 1;
+
+        assert!(!verify_content_signature(&address, &content_hash, &hex::encode(wrapped)).unwrap());
+        assert!(!verify_content_signature(&address, &content_hash, &hex::encode(raw)).unwrap());
+    }
+
+    #[test]
+    fn rejects_signature_from_another_account() {
+        let (keypair, _) = alice();
+        let bob = create_keypair_from_mnemonic("//Bob").unwrap();
+        let bob_address = AccountId32::from(bob.public_key()).to_string();
+        let content_hash = hash_content("article body");
+        let signature = sign_content_hash(&keypair, &content_hash).unwrap();
+
+        assert!(!verify_content_signature(&bob_address, &content_hash, &hex::encode(signature.0)).unwrap());
+    }
+
+    #[test]
+    fn reports_malformed_inputs() {
+        let (_, address) = alice();
+        let content_hash = hash_content("article body");
+
+        assert!(matches!(
+            verify_content_signature("not-an-address", &content_hash, &"00".repeat(64)),
+            Err(EduNewsError::InvalidAddress { .. })
+        ));
+        assert!(matches!(
+            verify_content_signature(&address, &content_hash, "0x1234"),
+            Err(EduNewsError::InvalidSignature { .. })
+        ));
+    }
+}